@@ -0,0 +1,96 @@
+//! Generate the embedded station table, `src/types/station/data.rs`, from the
+//! `airports.json` file of the [mwgg/Airports] dataset, which is available
+//! under the MIT licence.
+//!
+//! ```sh
+//! curl -LO https://raw.githubusercontent.com/mwgg/Airports/master/airports.json
+//! cargo run --example generate_stations --features stations -- airports.json > src/types/station/data.rs
+//! ```
+//!
+//! Airports without a valid ICAO identifier or IANA time zone are skipped.
+//!
+//! [mwgg/Airports]: https://github.com/mwgg/Airports
+
+use std::{collections::BTreeMap, env, fmt::Write, fs};
+
+use anyhow::Context;
+use chrono_tz::Tz;
+use metar::Icao;
+use serde_json::Value;
+
+fn main() -> anyhow::Result<()> {
+    let path = env::args()
+        .nth(1)
+        .context("usage: generate_stations <airports.json>")?;
+    let airports: BTreeMap<String, Value> = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+    let mut stations = BTreeMap::new();
+    for airport in airports.values() {
+        if let Some(station) = station(airport) {
+            stations.insert(station.0, station.1);
+        }
+    }
+
+    print!(
+        "\
+// Generated by `examples/generate_stations.rs` from the mwgg/Airports dataset,
+// https://github.com/mwgg/Airports, which is available under the MIT licence.
+// Regenerate this file rather than editing it by hand.
+
+use chrono_tz::Tz;
+
+use super::Station;
+use crate::Icao;
+
+/// The embedded station table, sorted by ICAO identifier
+#[rustfmt::skip]
+pub(super) static STATIONS: &[Station] = &[
+"
+    );
+    for line in stations.values() {
+        println!("{line}");
+    }
+    println!("];");
+    Ok(())
+}
+
+/// Format an airport as an entry of the station table, keyed by its ICAO
+/// identifier, or [`None`] if it is missing any fields
+fn station(airport: &Value) -> Option<(Icao, String)> {
+    let icao: Icao = airport["icao"].as_str()?.parse().ok()?;
+    let name = airport["name"].as_str()?;
+    let latitude = airport["lat"].as_f64()?;
+    let longitude = airport["lon"].as_f64()?;
+    let elevation = airport["elevation"].as_i64()?;
+    let country = airport["country"].as_str().filter(|c| c.len() == 2)?;
+    let timezone: Tz = airport["tz"].as_str()?.parse().ok()?;
+
+    let mut line = String::new();
+    write!(
+        line,
+        "    Station {{ icao: Icao(*b\"{icao}\"), name: {name:?}, latitude: {latitude:?}, \
+         longitude: {longitude:?}, elevation: {elevation}, country: {country:?}, \
+         timezone: Tz::{} }},",
+        variant(timezone)
+    )
+    .ok()?;
+    Some((icao, line))
+}
+
+/// The name of the [`Tz`] variant for a time zone, for example
+/// `Europe__London` for `Europe/London`
+fn variant(timezone: Tz) -> String {
+    let name = timezone.name();
+    let mut variant = String::with_capacity(name.len());
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' => variant.push_str("__"),
+            '+' => variant.push_str("Plus"),
+            '-' if chars.peek().is_some_and(char::is_ascii_digit) => variant.push_str("Minus"),
+            '-' => {}
+            c => variant.push(c),
+        }
+    }
+    variant
+}
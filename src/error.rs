@@ -197,15 +197,47 @@ pub enum ErrorVariant {
     #[display("invalid observation minute")]
     InvalidMinute,
 
+    // STATION //
+    #[display("invalid ICAO station identifier")]
+    InvalidIcao,
+
     // WIND //
     #[display("invalid wind heading")]
     InvalidWindHeading,
 
+    // VISIBILITY //
+    #[display("invalid visibility fraction")]
+    InvalidVisibilityFraction,
+
+    // WEATHER //
+    #[display("invalid weather group")]
+    InvalidWeather,
+
+    // TREND //
+    #[display("invalid trend time")]
+    InvalidTrendTime,
+    #[display("invalid trend probability")]
+    InvalidTrendProbability,
+
     // RVR //
     #[display("invalid runway number in RVR")]
     InvalidRvrRunwayNumber,
     #[display("invalid distance in RVR")]
     InvalidRvrDistance,
+
+    // RUNWAY CONDITION //
+    #[display("invalid runway deposit depth")]
+    InvalidRunwayDepositDepth,
+    #[display("invalid runway braking action")]
+    InvalidRunwayBrakingAction,
+    #[display("invalid runway condition code")]
+    InvalidRunwayConditionCode,
+    #[display("invalid runway number in runway state")]
+    InvalidRunwayStateRunwayNumber,
+    #[display("invalid runway contaminant coverage")]
+    InvalidRunwayCoverage,
+    #[display("invalid runway contaminant depth")]
+    InvalidRunwayDepth,
 }
 
 impl ErrorVariant {
@@ -241,16 +273,61 @@ impl ErrorVariant {
                 Cow::Borrowed("the observation date must be a two digit number less than 60")
             }
 
+            // STATION //
+            Self::InvalidIcao => {
+                Cow::Borrowed("the station identifier must be four letters or digits")
+            }
+
             // WIND //
             Self::InvalidWindHeading => {
                 Cow::Borrowed("the wind heading must be three digits between 000 and 360 inclusive")
             }
 
+            // VISIBILITY //
+            Self::InvalidVisibilityFraction => {
+                Cow::Borrowed("the denominator of a visibility fraction must not be zero")
+            }
+
+            // WEATHER //
+            Self::InvalidWeather => Cow::Borrowed(
+                "the weather group must be an intensity followed by descriptors, phenomena or both",
+            ),
+
+            // TREND //
+            Self::InvalidTrendTime => Cow::Borrowed(
+                "the trend time must be four digits in 24 hour format, between 0000 and 2400",
+            ),
+            Self::InvalidTrendProbability => {
+                Cow::Borrowed("the trend probability must be 30 or 40 percent")
+            }
+
             // RVR //
             Self::InvalidRvrRunwayNumber => Cow::Borrowed(
-                r#"the runway number must be between 00 and 36, and may be suffixed with "L", "C" or "R""#,
+                r#"the runway number must be between 00 and 36, and may be suffixed with "L", "C" or "R", or be 88 or 99"#,
+            ),
+            Self::InvalidRvrDistance => {
+                Cow::Borrowed("the RVR distance must be a 3 or 4 digit number")
+            }
+
+            // RUNWAY CONDITION //
+            Self::InvalidRunwayDepositDepth => Cow::Borrowed(
+                "the deposit depth must be two digits between 00 and 90, or between 92 and 99",
+            ),
+            Self::InvalidRunwayBrakingAction => Cow::Borrowed(
+                "the braking action must be two digits between 00 and 95, or 99",
+            ),
+            Self::InvalidRunwayConditionCode => {
+                Cow::Borrowed("the runway condition code must be a digit between 0 and 6")
+            }
+            Self::InvalidRunwayStateRunwayNumber => Cow::Borrowed(
+                "the runway number must be two digits between 01 and 36, with 50 added for right hand runways, or be 88 or 99",
+            ),
+            Self::InvalidRunwayCoverage => Cow::Borrowed(
+                "the contaminant coverage must be a percentage between 0 and 100, or NR",
+            ),
+            Self::InvalidRunwayDepth => Cow::Borrowed(
+                "the contaminant depth must be up to three digits in millimetres, or NR",
             ),
-            Self::InvalidRvrDistance => Cow::Borrowed("the RVR distance must be a 4 digit number"),
         }
     }
 }
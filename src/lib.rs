@@ -28,6 +28,8 @@
 //! correctly, please open an issue and include the METAR. This will aid in debugging
 //! the issue significantly.
 
+pub use chrono;
+
 mod error;
 pub use error::{ErrorVariant, MetarError, OwnedMetarError};
 
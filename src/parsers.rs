@@ -1,30 +1,11 @@
 use chumsky::prelude::*;
 
-use crate::ErrorVariant;
+use crate::{traits::Parsable, Runway};
 
-/// Parse a runway number
+/// Parse a runway number, prefixed with `R`
 pub(crate) fn runway_number<'src>(
-) -> impl Parser<'src, &'src str, String, extra::Err<crate::MetarError<'src>>> {
-    group((
-        just("R"),
-        text::digits(10)
-            .at_least(1)
-            .at_most(2)
-            .to_slice()
-            .try_map(|d: &str, span| {
-                if !d.parse::<u8>().is_ok_and(|v| v <= 36 || v == 88) {
-                    return Err(ErrorVariant::InvalidRvrRunwayNumber.into_err(span));
-                }
-                Ok(d)
-            }),
-        choice((
-            just("L").map(|_| "L"),
-            just("C").map(|_| "C"),
-            just("R").map(|_| "R"),
-            empty().map(|()| ""),
-        )),
-    ))
-    .map(|(_, rwy, suffix)| format!("{rwy}{suffix}"))
+) -> impl Parser<'src, &'src str, Runway, extra::Err<crate::MetarError<'src>>> {
+    just("R").ignore_then(Runway::parser())
 }
 
 /// Match and parse any whitespace, including none
@@ -39,7 +20,18 @@ pub(crate) fn some_whitespace<'src>(
     text::inline_whitespace().at_least(1).or(end())
 }
 
-/// Match and parse some whitespace, demanding at least one character of whitespace
+/// Check that the current group has ended, without consuming any input
+pub(crate) fn end_of_group<'src>(
+) -> impl Parser<'src, &'src str, (), extra::Err<crate::MetarError<'src>>> + Clone {
+    choice((
+        text::inline_whitespace().at_least(1),
+        just("=").map(|_| ()),
+        end(),
+    ))
+    .rewind()
+}
+
+/// Match and parse a temperature, which may be negative if prefixed with `M`
 pub(crate) fn temperature<'src>(
 ) -> impl Parser<'src, &'src str, i32, extra::Err<crate::MetarError<'src>>> {
     choice((
@@ -5,25 +5,49 @@ mod cloud_layer;
 pub use cloud_layer::{CloudDensity, CloudLayer};
 
 mod cloud_state;
-pub use cloud_state::Clouds;
+pub use cloud_state::{CloudInconsistency, Clouds};
 
 mod cloud_type;
 pub use cloud_type::CloudType;
 
 mod colour_code;
-pub use colour_code::ColourCode;
+pub use colour_code::{ColourCode, ColourState};
 
 mod data;
 pub use data::Data;
 
+mod daylight;
+pub use daylight::{Daylight, SunTimes};
+
+mod effective_conditions;
+pub use effective_conditions::EffectiveConditions;
+
+mod fraction;
+pub use fraction::Fraction;
+
+mod hazard;
+pub use hazard::Hazard;
+
+mod icao;
+pub use icao::{Icao, IcaoRegion};
+
 mod kind;
-pub use kind::Kind;
+pub use kind::{Correction, Kind};
 
 mod pressure;
 pub use pressure::Pressure;
 
+mod projection;
+pub use projection::ProjectedConditions;
+
+mod runway;
+pub use runway::{Runway, RunwayDesignator};
+
 mod runway_condition;
-pub use runway_condition::{RunwayCondition, RunwayContamination, RunwayDeposits};
+pub use runway_condition::{
+    BrakingAction, DepositDepth, RunwayCondition, RunwayContamination, RunwayDeposits,
+    RunwayThirdCondition,
+};
 
 mod rvr;
 pub use rvr::{RunwayVisualRange, RvrTrend, RvrUnit, RvrValue, RvrValueInner};
@@ -31,12 +55,23 @@ pub use rvr::{RunwayVisualRange, RvrTrend, RvrUnit, RvrValue, RvrValueInner};
 mod sea_condition;
 pub use sea_condition::{SeaCondition, SeaConditionInner, SeaState};
 
+mod staleness;
+pub use staleness::{Staleness, StalenessThresholds};
+
+#[cfg(feature = "stations")]
+mod station;
+#[cfg(feature = "stations")]
+pub use station::Station;
+
 mod time;
 pub use time::Time;
 
 mod trend;
 pub use trend::{Trend, TrendNewCondition, TrendTime};
 
+mod verification;
+pub use verification::{ElementScore, TrendVerification};
+
 mod visibility;
 pub use visibility::{CompassDirection, Visibility};
 
@@ -47,10 +82,13 @@ mod weather;
 pub use weather::Weather;
 
 mod weather_condition;
-pub use weather_condition::WeatherCondition;
+pub use weather_condition::{WeatherCategory, WeatherCondition};
+
+mod weather_descriptor;
+pub use weather_descriptor::WeatherDescriptor;
 
 mod wind;
-pub use wind::Wind;
+pub use wind::{Wind, WindInconsistency};
 
 mod weather_intensity;
 pub use weather_intensity::WeatherIntensity;
@@ -62,4 +100,4 @@ mod wind_speed;
 pub use wind_speed::WindSpeed;
 
 mod windshear_warnings;
-pub use windshear_warnings::{WindshearGroup, WindshearWarnings};
+pub use windshear_warnings::{WindshearGroup, WindshearPhase, WindshearWarnings};
@@ -4,7 +4,7 @@ use crate::{traits::Parsable, Data};
 
 use super::CloudType;
 
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Cloud cover
 pub struct CloudLayer {
@@ -12,13 +12,41 @@ pub struct CloudLayer {
     pub density: Data<CloudDensity>,
     /// Cloud type
     pub kind: Data<CloudType>,
-    /// Cloud height in feet
+    /// Cloud height in hundreds of feet
     pub height: Data<u32>,
 }
 
+impl CloudLayer {
+    /// The height of the base of this layer in feet, if known
+    #[must_use]
+    pub fn height_feet(&self) -> Option<u32> {
+        match self.height {
+            Data::Known(height) => Some(height * 100),
+            Data::Unknown => None,
+        }
+    }
+
+    /// The height of the base of this layer in metres, rounded to the nearest
+    /// metre, if known
+    #[must_use]
+    pub fn height_metres(&self) -> Option<u32> {
+        // 1ft is exactly 0.3048m
+        self.height_feet().map(|feet| (feet * 3048 + 5000) / 10_000)
+    }
+
+    /// Is this a cumulonimbus or towering cumulus layer?
+    #[must_use]
+    pub fn is_convective(&self) -> bool {
+        matches!(
+            self.kind,
+            Data::Known(CloudType::Cumulonimbus | CloudType::ToweringCumulus)
+        )
+    }
+}
+
 impl Parsable for CloudLayer {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
-        group((
+        let layer = group((
             Data::parser_inline(3, CloudDensity::parser()),
             Data::parser_inline(
                 3,
@@ -33,12 +61,28 @@ impl Parsable for CloudLayer {
             density,
             kind,
             height,
-        })
+        });
+
+        choice((
+            layer,
+            // Convective cloud detected by an automatic station, with the
+            // density and height omitted, such as `///CB`
+            just("///")
+                .ignore_then(choice((
+                    just("CB").map(|_| CloudType::Cumulonimbus),
+                    just("TCU").map(|_| CloudType::ToweringCumulus),
+                )))
+                .map(|kind| CloudLayer {
+                    density: Data::Unknown,
+                    kind: Data::Known(kind),
+                    height: Data::Unknown,
+                }),
+        ))
     }
 }
 
-/// The density of the cloud cover
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+/// The density of the cloud cover, ordered from least to most dense
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CloudDensity {
     /// Few clouds (1/8)
@@ -76,6 +120,30 @@ mod tests {
                 kind: Data::Known(CloudType::Cumulonimbus),
             }
         );
+        assert_eq!(
+            CloudLayer::parse("///CB").unwrap(),
+            CloudLayer {
+                density: Data::Unknown,
+                height: Data::Unknown,
+                kind: Data::Known(CloudType::Cumulonimbus),
+            }
+        );
+        assert_eq!(
+            CloudLayer::parse("BKN///TCU").unwrap(),
+            CloudLayer {
+                density: Data::Known(CloudDensity::Broken),
+                height: Data::Unknown,
+                kind: Data::Known(CloudType::ToweringCumulus),
+            }
+        );
+        assert_eq!(
+            CloudLayer::parse("FEW///").unwrap(),
+            CloudLayer {
+                density: Data::Known(CloudDensity::Few),
+                height: Data::Unknown,
+                kind: Data::Known(CloudType::Normal),
+            }
+        );
         assert_eq!(
             CloudLayer::parse("/////////").unwrap(),
             CloudLayer {
@@ -85,4 +153,14 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_heights() {
+        let layer = CloudLayer::parse("SCT035").unwrap();
+        assert_eq!(layer.height_feet(), Some(3500));
+        assert_eq!(layer.height_metres(), Some(1067));
+        assert!(!layer.is_convective());
+        assert!(CloudLayer::parse("///TCU").unwrap().is_convective());
+        assert_eq!(CloudLayer::parse("FEW///").unwrap().height_feet(), None);
+    }
 }
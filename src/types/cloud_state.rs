@@ -1,32 +1,137 @@
 use chumsky::prelude::*;
 
-use crate::{parsers::some_whitespace, traits::Parsable};
+use crate::{parsers::some_whitespace, traits::Parsable, CloudDensity, CloudLayer, Data};
 
-#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-/// Cloud state
+/// The state of the sky
 pub enum Clouds {
-    /// No cloud was detected, also set for CAVOK
+    /// The sky is clear, as reported by an observer (SKC)
+    SkyClear,
+    /// No cloud was detected below 12,000ft by an automatic station (CLR)
+    Clear,
+    /// No cloud was detected by an automatic station (NCD)
     NoCloudDetected,
-    /// No significant cloud was detected below 5000ft
+    /// No cloud of operational significance, also implied by CAVOK (NSC)
     NoSignificantCloud,
-    /// Layers of cloud, described elsewhere
-    CloudLayers,
+    /// Layers of cloud. This is empty if no cloud information was reported.
+    CloudLayers(Vec<CloudLayer>),
+}
+
+/// An inconsistency in the reported cloud layers, which suggests they have
+/// been reported or decoded incorrectly
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CloudInconsistency {
+    /// The layers are not in ascending order of height
+    NotAscending,
+    /// A layer is less dense than the reporting rules allow: the second
+    /// layer must be at least scattered and any further layers at least
+    /// broken, except for cumulonimbus and towering cumulus layers
+    DensityBelowReportingRules,
+}
+
+impl Clouds {
+    /// Get the cloud layers, which is empty unless this is [`Clouds::CloudLayers`]
+    #[must_use]
+    pub fn layers(&self) -> &[CloudLayer] {
+        match self {
+            Self::CloudLayers(layers) => layers,
+            _ => &[],
+        }
+    }
+
+    /// Check the cloud layers for inconsistencies. An empty list means that
+    /// no inconsistencies were found.
+    #[must_use]
+    pub fn inconsistencies(&self) -> Vec<CloudInconsistency> {
+        let mut inconsistencies = vec![];
+
+        let heights = self
+            .layers()
+            .iter()
+            .filter_map(CloudLayer::height_feet)
+            .collect::<Vec<_>>();
+        if heights.windows(2).any(|pair| pair[0] > pair[1]) {
+            inconsistencies.push(CloudInconsistency::NotAscending);
+        }
+
+        let minimum_densities = [
+            CloudDensity::Few,
+            CloudDensity::Scattered,
+            CloudDensity::Broken,
+        ];
+        let below_rules = self
+            .layers()
+            .iter()
+            .filter(|layer| !layer.is_convective())
+            .enumerate()
+            .any(|(idx, layer)| match layer.density {
+                Data::Known(density) => density < minimum_densities[idx.min(2)],
+                Data::Unknown => false,
+            });
+        if below_rules {
+            inconsistencies.push(CloudInconsistency::DensityBelowReportingRules);
+        }
+
+        inconsistencies
+    }
 }
 
 impl Parsable for Clouds {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         choice((
+            just("SKC")
+                .map(|_| Clouds::SkyClear)
+                .then_ignore(some_whitespace()),
+            just("CLR")
+                .map(|_| Clouds::Clear)
+                .then_ignore(some_whitespace()),
             just("NCD")
                 .map(|_| Clouds::NoCloudDetected)
                 .then_ignore(some_whitespace()),
             just("NSC")
                 .map(|_| Clouds::NoSignificantCloud)
                 .then_ignore(some_whitespace()),
-            just("CLR")
-                .map(|_| Clouds::NoCloudDetected)
-                .then_ignore(some_whitespace()),
-            empty().map(|()| Clouds::CloudLayers),
+            CloudLayer::parser()
+                .separated_by(some_whitespace())
+                .allow_trailing()
+                .collect::<Vec<_>>()
+                .map(Clouds::CloudLayers),
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sky_condition() {
+        assert_eq!(Clouds::parse("SKC").unwrap(), Clouds::SkyClear);
+        assert_eq!(Clouds::parse("CLR").unwrap(), Clouds::Clear);
+        assert_eq!(Clouds::parse("NCD").unwrap(), Clouds::NoCloudDetected);
+        assert_eq!(Clouds::parse("NSC").unwrap(), Clouds::NoSignificantCloud);
+        assert_eq!(Clouds::parse("FEW015 BKN030").unwrap().layers().len(), 2);
+    }
+
+    #[test]
+    fn test_inconsistencies() {
+        let inconsistencies = |clouds: &str| Clouds::parse(clouds).unwrap().inconsistencies();
+        assert!(inconsistencies("FEW015 FEW026CB BKN030").is_empty());
+        assert!(inconsistencies("FEW020CB FEW022 BKN300").is_empty());
+        assert!(inconsistencies("SCT035/// //////CB").is_empty());
+        assert_eq!(
+            inconsistencies("BKN030 SCT020"),
+            vec![CloudInconsistency::NotAscending]
+        );
+        assert_eq!(
+            inconsistencies("FEW010 FEW020"),
+            vec![CloudInconsistency::DensityBelowReportingRules]
+        );
+        assert_eq!(
+            inconsistencies("FEW010 SCT020 SCT030"),
+            vec![CloudInconsistency::DensityBelowReportingRules]
+        );
+    }
+}
@@ -2,8 +2,8 @@ use chumsky::prelude::*;
 
 use crate::traits::Parsable;
 
-/// Military airport colour code
-#[derive(PartialEq, Eq, Clone, Debug)]
+/// Military airport colour code, ordered from the best conditions to the worst.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColourCode {
     /// 2500ft cloud base, 8000m visibility
@@ -20,6 +20,45 @@ pub enum ColourCode {
     Red,
 }
 
+impl ColourCode {
+    /// The minimum visibility (in metres) and cloud base (in feet) required for
+    /// this colour code, or [`None`] for [`ColourCode::Red`] which has no minimums.
+    #[must_use]
+    pub fn minimums(self) -> Option<(u32, u32)> {
+        match self {
+            Self::Blue => Some((8000, 2500)),
+            Self::White => Some((5000, 1500)),
+            Self::Green => Some((3700, 700)),
+            Self::Yellow => Some((1600, 300)),
+            Self::Amber => Some((800, 200)),
+            Self::Red => None,
+        }
+    }
+
+    /// Compute the colour code for a visibility in metres and the base of the
+    /// lowest cloud layer of scattered or worse, in feet. A cloud base of
+    /// [`None`] indicates that there is no such layer.
+    #[must_use]
+    pub fn from_conditions(visibility_metres: f32, cloud_base_feet: Option<u32>) -> Self {
+        [
+            Self::Blue,
+            Self::White,
+            Self::Green,
+            Self::Yellow,
+            Self::Amber,
+        ]
+        .into_iter()
+        .find(|code| {
+            code.minimums().is_some_and(|(min_vis, min_base)| {
+                #[allow(clippy::cast_precision_loss, reason = "minimums are small")]
+                let vis_ok = visibility_metres >= min_vis as f32;
+                vis_ok && cloud_base_feet.is_none_or(|base| base >= min_base)
+            })
+        })
+        .unwrap_or(Self::Red)
+    }
+}
+
 impl Parsable for ColourCode {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         choice((
@@ -32,3 +71,66 @@ impl Parsable for ColourCode {
         ))
     }
 }
+
+/// A reported military colour state, as a colour code optionally prefixed by `BLACK`
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColourState {
+    /// The airfield is unusable for reasons other than the weather (`BLACK`)
+    pub black: bool,
+    /// The colour code describing the weather conditions
+    pub colour: ColourCode,
+}
+
+impl Parsable for ColourState {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        just("BLACK")
+            .or_not()
+            .then(ColourCode::parser())
+            .map(|(black, colour)| ColourState {
+                black: black.is_some(),
+                colour,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colour_state() {
+        assert_eq!(
+            ColourState::parse("GRN").unwrap(),
+            ColourState {
+                black: false,
+                colour: ColourCode::Green,
+            }
+        );
+        assert_eq!(
+            ColourState::parse("BLACKBLU").unwrap(),
+            ColourState {
+                black: true,
+                colour: ColourCode::Blue,
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_conditions() {
+        assert_eq!(ColourCode::from_conditions(9999., None), ColourCode::Blue);
+        assert_eq!(
+            ColourCode::from_conditions(9999., Some(2000)),
+            ColourCode::White
+        );
+        assert_eq!(
+            ColourCode::from_conditions(4000., Some(3000)),
+            ColourCode::Green
+        );
+        assert_eq!(
+            ColourCode::from_conditions(800., Some(200)),
+            ColourCode::Amber
+        );
+        assert_eq!(ColourCode::from_conditions(500., None), ColourCode::Red);
+    }
+}
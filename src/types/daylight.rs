@@ -0,0 +1,218 @@
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+
+/// The altitude of the centre of the sun at sunrise and sunset, in degrees,
+/// allowing for refraction and the radius of the sun
+const SUNRISE_ALTITUDE: f64 = -0.833;
+/// The altitude of the centre of the sun at the start and end of civil
+/// twilight, in degrees
+const CIVIL_TWILIGHT_ALTITUDE: f64 = -6.0;
+/// The Julian date of the J2000 epoch
+const J2000: f64 = 2_451_545.0;
+/// The Julian date of the Unix epoch
+const UNIX_EPOCH_JULIAN_DATE: f64 = 2_440_587.5;
+
+/// Whether it is day or night at a location
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Daylight {
+    /// The sun is above the horizon
+    Day,
+    /// The sun is below the horizon, but by no more than 6°
+    CivilTwilight,
+    /// The sun is more than 6° below the horizon. This is night as defined by
+    /// ICAO, from the end of evening civil twilight to the beginning of
+    /// morning civil twilight.
+    Night,
+}
+
+impl Daylight {
+    /// Determine whether it is day or night at the given time and location,
+    /// in degrees
+    #[must_use]
+    pub fn at(time: DateTime<Utc>, latitude: f64, longitude: f64) -> Self {
+        let elevation = solar_elevation(time, latitude, longitude);
+        if elevation >= SUNRISE_ALTITUDE {
+            Self::Day
+        } else if elevation >= CIVIL_TWILIGHT_ALTITUDE {
+            Self::CivilTwilight
+        } else {
+            Self::Night
+        }
+    }
+
+    /// Is it night?
+    #[must_use]
+    pub fn is_night(self) -> bool {
+        self == Self::Night
+    }
+}
+
+/// The times of sunrise, sunset and civil twilight on a day at a location.
+///
+/// An event is [`None`] if it does not happen on that day, for example during
+/// polar day or night.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SunTimes {
+    /// The start of morning civil twilight
+    pub civil_dawn: Option<DateTime<Utc>>,
+    /// Sunrise
+    pub sunrise: Option<DateTime<Utc>>,
+    /// Sunset
+    pub sunset: Option<DateTime<Utc>>,
+    /// The end of evening civil twilight
+    pub civil_dusk: Option<DateTime<Utc>>,
+}
+
+impl SunTimes {
+    /// Calculate the sun times on a date at a location, in degrees. The date
+    /// is the UTC date of local solar noon.
+    #[must_use]
+    pub fn on(date: NaiveDate, latitude: f64, longitude: f64) -> Self {
+        let event = |altitude, rising| sun_event(date, latitude, longitude, altitude, rising);
+        Self {
+            civil_dawn: event(CIVIL_TWILIGHT_ALTITUDE, true),
+            sunrise: event(SUNRISE_ALTITUDE, true),
+            sunset: event(SUNRISE_ALTITUDE, false),
+            civil_dusk: event(CIVIL_TWILIGHT_ALTITUDE, false),
+        }
+    }
+}
+
+/// The position of the sun at a time, as its declination and the equation of
+/// time, both in degrees
+fn solar_position(time: DateTime<Utc>) -> (f64, f64) {
+    #[allow(clippy::cast_precision_loss, reason = "timestamps fit within f64")]
+    let n = time.timestamp() as f64 / 86400. + UNIX_EPOCH_JULIAN_DATE - J2000;
+
+    let mean_longitude = 280.460 + 0.985_647_4 * n;
+    let mean_anomaly = (357.528 + 0.985_600_3 * n).to_radians();
+    let ecliptic_longitude =
+        (mean_longitude + 1.915 * mean_anomaly.sin() + 0.020 * (2. * mean_anomaly).sin())
+            .to_radians();
+    let obliquity = (23.439 - 0.000_000_4 * n).to_radians();
+
+    let declination = (obliquity.sin() * ecliptic_longitude.sin()).asin();
+    let right_ascension =
+        (obliquity.cos() * ecliptic_longitude.sin()).atan2(ecliptic_longitude.cos());
+    let equation_of_time =
+        (mean_longitude - right_ascension.to_degrees() + 180.).rem_euclid(360.) - 180.;
+
+    (declination.to_degrees(), equation_of_time)
+}
+
+/// The elevation of the sun above the horizon at a time and location, in degrees
+fn solar_elevation(time: DateTime<Utc>, latitude: f64, longitude: f64) -> f64 {
+    let (declination, equation_of_time) = solar_position(time);
+    let day_start = time.date_naive().and_time(chrono::NaiveTime::MIN).and_utc();
+    #[allow(
+        clippy::cast_precision_loss,
+        reason = "seconds in a day fit within f64"
+    )]
+    let utc_degrees = (time - day_start).num_seconds() as f64 / 240.;
+    let hour_angle = (utc_degrees + equation_of_time + longitude - 180.).to_radians();
+
+    let (latitude, declination) = (latitude.to_radians(), declination.to_radians());
+    (latitude.sin() * declination.sin() + latitude.cos() * declination.cos() * hour_angle.cos())
+        .asin()
+        .to_degrees()
+}
+
+/// The time the sun crosses the given altitude on a date, either rising or
+/// setting, or [`None`] if it does not
+fn sun_event(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    rising: bool,
+) -> Option<DateTime<Utc>> {
+    let midnight = date.and_time(chrono::NaiveTime::MIN).and_utc();
+    let at_degrees = |degrees: f64| {
+        #[allow(clippy::cast_possible_truncation, reason = "bounded to a few days")]
+        let seconds = (degrees * 240.).round() as i64;
+        midnight + TimeDelta::seconds(seconds)
+    };
+
+    // Start from local solar noon, then refine using the sun's position at
+    // the estimated time of the event
+    let mut estimate = at_degrees(180. - longitude);
+    for _ in 0..2 {
+        let (declination, equation_of_time) = solar_position(estimate);
+        let (lat, dec) = (latitude.to_radians(), declination.to_radians());
+        let cos_hour_angle =
+            (altitude.to_radians().sin() - lat.sin() * dec.sin()) / (lat.cos() * dec.cos());
+        if !(-1. ..=1.).contains(&cos_hour_angle) {
+            return None;
+        }
+        let hour_angle = cos_hour_angle.acos().to_degrees();
+        let noon = 180. - longitude - equation_of_time;
+        estimate = at_degrees(if rising {
+            noon - hour_angle
+        } else {
+            noon + hour_angle
+        });
+    }
+    Some(estimate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Metar;
+    use chrono::FixedOffset;
+
+    fn assert_near(actual: Option<DateTime<Utc>>, expected: &str) {
+        let expected: DateTime<Utc> = expected.parse().unwrap();
+        let difference = (actual.unwrap() - expected).num_seconds().abs();
+        assert!(difference <= 180, "{actual:?} is not near {expected}");
+    }
+
+    #[test]
+    fn test_sun_times() {
+        // London, midsummer
+        let times = SunTimes::on(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(), 51.5, -0.12);
+        assert_near(times.sunrise, "2024-06-21T03:43:00Z");
+        assert_near(times.sunset, "2024-06-21T20:21:00Z");
+        assert!(times.civil_dawn < times.sunrise);
+        assert!(times.civil_dusk > times.sunset);
+
+        // Tromsø, polar night
+        let times = SunTimes::on(NaiveDate::from_ymd_opt(2024, 12, 21).unwrap(), 69.65, 18.96);
+        assert_eq!(times.sunrise, None);
+        assert!(times.civil_dawn.is_some());
+    }
+
+    #[test]
+    fn test_daylight() {
+        let metar = Metar::parse("EGLL 212350Z 24005KT CAVOK 15/10 Q1020").unwrap();
+        let reference = "2024-06-22T00:00:00Z".parse().unwrap();
+        assert_eq!(
+            metar.daylight(reference, 51.47, -0.46),
+            Some(Daylight::Night)
+        );
+        assert!(metar
+            .daylight(reference, 51.47, -0.46)
+            .is_some_and(Daylight::is_night));
+
+        let noon = "2024-06-21T12:00:00Z".parse().unwrap();
+        assert_eq!(Daylight::at(noon, 51.47, -0.46), Daylight::Day);
+        let dusk = "2024-06-21T20:40:00Z".parse().unwrap();
+        assert_eq!(Daylight::at(dusk, 51.47, -0.46), Daylight::CivilTwilight);
+    }
+
+    #[test]
+    fn test_local_time() {
+        let metar = Metar::parse("KJFK 121851Z 18012KT 10SM FEW250 24/13 A3003").unwrap();
+        let reference = "2024-07-12T19:00:00Z".parse().unwrap();
+        let eastern = FixedOffset::west_opt(4 * 3600).unwrap();
+        assert_eq!(
+            metar.local_time(reference, &eastern).unwrap().to_rfc3339(),
+            "2024-07-12T14:51:00-04:00"
+        );
+        assert_eq!(
+            metar.local_mean_time(reference, -75.).unwrap().to_rfc3339(),
+            "2024-07-12T13:51:00-05:00"
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    CloudDensity, CloudLayer, Clouds, Data, TrendNewCondition, VerticalVisibility, Visibility,
+    Weather, Wind, WindSpeed,
+};
+
+/// The wind, visibility, weather and cloud conditions implied by a report, with
+/// [`Visibility::CAVOK`] expanded into its meaning: a visibility of 10km or
+/// more, no cloud below 5000ft or the highest minimum sector altitude, no
+/// cumulonimbus or towering cumulus, and no significant weather.
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EffectiveConditions {
+    /// The wind
+    pub wind: Wind,
+    /// The visibility, which is never [`Visibility::CAVOK`]
+    pub visibility: Data<Visibility>,
+    /// The weather conditions
+    pub weather: Data<Vec<Weather>>,
+    /// The vertical visibility, in hundreds of feet
+    pub vert_visibility: Option<VerticalVisibility>,
+    /// The state of the sky
+    pub clouds: Clouds,
+}
+
+impl EffectiveConditions {
+    /// Expand the given conditions, replacing CAVOK with the conditions it implies
+    #[must_use]
+    pub fn expand(
+        wind: Wind,
+        visibility: Data<Visibility>,
+        weather: Data<Vec<Weather>>,
+        vert_visibility: Option<VerticalVisibility>,
+        clouds: Clouds,
+    ) -> Self {
+        if visibility == Data::Known(Visibility::CAVOK) {
+            return Self {
+                wind,
+                visibility: Data::Known(Visibility::TenKilometresOrMore),
+                weather: Data::Known(vec![]),
+                vert_visibility: None,
+                clouds: Clouds::NoSignificantCloud,
+            };
+        }
+        Self {
+            wind,
+            visibility,
+            weather,
+            vert_visibility,
+            clouds,
+        }
+    }
+
+    /// The base of the lowest cloud layer that is scattered or worse, in
+    /// feet. A reported vertical visibility is treated as a cloud base.
+    ///
+    /// Returns [`None`] if there is no such layer, or its height is unknown.
+    #[must_use]
+    pub fn cloud_base_feet(&self) -> Option<u32> {
+        let lowest_layer = self
+            .clouds
+            .layers()
+            .iter()
+            .filter(|layer| {
+                matches!(
+                    layer.density,
+                    Data::Known(
+                        CloudDensity::Scattered | CloudDensity::Broken | CloudDensity::Overcast
+                    )
+                )
+            })
+            .filter_map(CloudLayer::height_feet)
+            .min();
+        let vertical_visibility = match self.vert_visibility {
+            Some(VerticalVisibility::Distance(height)) => Some(height * 100),
+            _ => None,
+        };
+        match (lowest_layer, vertical_visibility) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Apply the changes forecast by a trend to these conditions. Elements
+    /// that the trend does not mention are unchanged, and a new sky condition
+    /// or vertical visibility replaces both. A forecast of CAVOK is expanded
+    /// as in [`EffectiveConditions::expand`].
+    #[must_use]
+    pub fn with_trend(&self, change: &TrendNewCondition) -> Self {
+        let mut conditions = self.clone();
+        if let Some(wind) = change.wind {
+            conditions.wind = wind;
+        }
+        if let Some(visibility) = change.visibility {
+            conditions.visibility = Data::Known(visibility);
+        }
+        if let Some(weather) = &change.weather {
+            conditions.weather = Data::Known(weather.clone());
+        }
+        if change.clouds.is_some() || change.vertical_visibility.is_some() {
+            conditions.clouds = change.clouds.clone().unwrap_or(Clouds::CloudLayers(vec![]));
+            conditions.vert_visibility = change.vertical_visibility;
+        }
+        Self::expand(
+            conditions.wind,
+            conditions.visibility,
+            conditions.weather,
+            conditions.vert_visibility,
+            conditions.clouds,
+        )
+    }
+
+    /// Combine these conditions with `other`, keeping the worse of each
+    /// element: the lower visibility, the stronger wind, and the weather and
+    /// cloud layers of both, with the lower vertical visibility. An unknown
+    /// element is replaced by a known one.
+    #[must_use]
+    pub fn worst_of(&self, other: &Self) -> Self {
+        let mut conditions = self.clone();
+
+        if wind_strength(other.wind) > wind_strength(self.wind) {
+            conditions.wind = other.wind;
+        }
+
+        conditions.visibility = match (self.visibility, other.visibility) {
+            (Data::Known(a), Data::Known(b)) if b.to_metres() < a.to_metres() => other.visibility,
+            (Data::Unknown, _) => other.visibility,
+            _ => self.visibility,
+        };
+
+        conditions.weather = match (&self.weather, &other.weather) {
+            (Data::Known(weather), Data::Known(added)) => {
+                let mut weather = weather.clone();
+                for wx in added {
+                    if !weather.contains(wx) {
+                        weather.push(wx.clone());
+                    }
+                }
+                Data::Known(weather)
+            }
+            (Data::Unknown, weather) | (weather, Data::Unknown) => weather.clone(),
+        };
+
+        conditions.clouds = match (&self.clouds, &other.clouds) {
+            (Clouds::CloudLayers(layers), Clouds::CloudLayers(added)) => {
+                let mut layers = layers.clone();
+                for layer in added {
+                    if !layers.contains(layer) {
+                        layers.push(*layer);
+                    }
+                }
+                layers.sort_by_key(CloudLayer::height_feet);
+                Clouds::CloudLayers(layers)
+            }
+            (_, Clouds::CloudLayers(added)) if !added.is_empty() => other.clouds.clone(),
+            _ => self.clouds.clone(),
+        };
+
+        let lower_vert_visibility = match (self.vert_visibility, other.vert_visibility) {
+            (Some(VerticalVisibility::Distance(a)), Some(VerticalVisibility::Distance(b))) => b < a,
+            (None, Some(_)) => true,
+            _ => false,
+        };
+        if lower_vert_visibility {
+            conditions.vert_visibility = other.vert_visibility;
+        }
+
+        conditions
+    }
+
+    /// Is any cumulonimbus or towering cumulus cloud reported?
+    #[must_use]
+    pub fn has_convective_cloud(&self) -> bool {
+        self.clouds.layers().iter().any(CloudLayer::is_convective)
+    }
+}
+
+/// The strength of a wind in knots, as its gust speed if given, otherwise its
+/// mean speed. A speed greater than the reported value is the strongest.
+fn wind_strength(wind: Wind) -> Option<f32> {
+    match wind {
+        Wind::Calm => Some(0.),
+        Wind::Present {
+            speed: WindSpeed::Greater,
+            ..
+        } => Some(f32::INFINITY),
+        Wind::Present { speed, .. } => speed.gust_knots().or(speed.speed_knots()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Metar;
+
+    #[test]
+    fn test_cavok() {
+        let metar = Metar::parse("EGHI 062050Z 31006KT 270V340 CAVOK 13/07 Q1017").unwrap();
+        assert_eq!(metar.clouds, Clouds::CloudLayers(vec![]));
+
+        let conditions = metar.effective_conditions();
+        assert_eq!(
+            conditions.visibility,
+            Data::Known(Visibility::TenKilometresOrMore)
+        );
+        assert_eq!(conditions.weather, Data::Known(vec![]));
+        assert_eq!(conditions.clouds, Clouds::NoSignificantCloud);
+        assert_eq!(conditions.cloud_base_feet(), None);
+        assert!(!conditions.has_convective_cloud());
+    }
+
+    #[test]
+    fn test_reported_conditions() {
+        let metar =
+            Metar::parse("EGLL 121150Z 24012KT 6000 -SHRA FEW008 SCT014CB BKN030 12/05 Q1020")
+                .unwrap();
+        let conditions = metar.effective_conditions();
+        assert_eq!(conditions.visibility, metar.visibility);
+        assert_eq!(conditions.clouds, metar.clouds);
+        assert_eq!(conditions.cloud_base_feet(), Some(1400));
+        assert!(conditions.has_convective_cloud());
+
+        let metar = Metar::parse("EGHI 150650Z 06001KT 0500 FG VV002 11/10 Q1003").unwrap();
+        assert_eq!(metar.effective_conditions().cloud_base_feet(), Some(200));
+    }
+
+    #[test]
+    fn test_sky_clear_is_not_cavok() {
+        let metar = Metar::parse("CYWG 190500Z 16014KT SKC 19/11 A2959").unwrap();
+        assert_eq!(metar.visibility, Data::Unknown);
+        assert_eq!(metar.clouds, Clouds::SkyClear);
+    }
+
+    #[test]
+    fn test_with_trend() {
+        let metar =
+            Metar::parse("EGLL 121150Z 24012KT 4000 -RA BKN012 12/05 Q1020 BECMG 9999 NSW SCT025")
+                .unwrap();
+        let crate::Trend::Becoming(change) = &metar.trends[0] else {
+            panic!("expected a becoming trend");
+        };
+        let conditions = metar.effective_conditions().with_trend(change);
+        assert_eq!(conditions.wind, metar.wind);
+        assert_eq!(
+            conditions.visibility,
+            Data::Known(Visibility::TenKilometresOrMore)
+        );
+        assert_eq!(conditions.weather, Data::Known(vec![]));
+        assert_eq!(conditions.cloud_base_feet(), Some(2500));
+    }
+
+    #[test]
+    fn test_with_cavok_trend() {
+        let metar =
+            Metar::parse("EGLL 121150Z 24012KT 0800 FG VV002 12/05 Q1020 TEMPO CAVOK").unwrap();
+        let crate::Trend::Temporarily(change) = &metar.trends[0] else {
+            panic!("expected a temporary trend");
+        };
+        let conditions = metar.effective_conditions().with_trend(change);
+        assert_eq!(
+            conditions.visibility,
+            Data::Known(Visibility::TenKilometresOrMore)
+        );
+        assert_eq!(conditions.weather, Data::Known(vec![]));
+        assert_eq!(conditions.vert_visibility, None);
+        assert_eq!(conditions.clouds, Clouds::NoSignificantCloud);
+    }
+}
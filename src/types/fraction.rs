@@ -0,0 +1,167 @@
+use std::{cmp::Ordering, fmt};
+
+/// An exact, non-negative fraction, such as a visibility of `2 1/2SM`.
+///
+/// Fractions are always stored in their lowest terms, so equal values compare
+/// and hash equally.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "FractionParts")
+)]
+pub struct Fraction {
+    numerator: u16,
+    denominator: u16,
+}
+
+impl Fraction {
+    /// Create a new fraction, reduced to its lowest terms.
+    ///
+    /// Returns [`None`] if the denominator is zero.
+    #[must_use]
+    pub fn new(numerator: u16, denominator: u16) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator);
+        Some(Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        })
+    }
+
+    /// Create a fraction from a mixed number, such as `2 1/2`.
+    ///
+    /// Returns [`None`] if the denominator is zero or the value is too large.
+    #[must_use]
+    pub fn from_mixed(whole: u16, numerator: u16, denominator: u16) -> Option<Self> {
+        let numerator = whole.checked_mul(denominator)?.checked_add(numerator)?;
+        Self::new(numerator, denominator)
+    }
+
+    /// The numerator, in lowest terms
+    #[must_use]
+    pub fn numerator(self) -> u16 {
+        self.numerator
+    }
+
+    /// The denominator, in lowest terms
+    #[must_use]
+    pub fn denominator(self) -> u16 {
+        self.denominator
+    }
+
+    /// The whole number part of this fraction
+    #[must_use]
+    pub fn whole_part(self) -> u16 {
+        self.numerator / self.denominator
+    }
+
+    /// Convert this fraction to a floating point number
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        f32::from(self.numerator) / f32::from(self.denominator)
+    }
+}
+
+impl From<u16> for Fraction {
+    fn from(value: u16) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+/// The parts of a fraction as serialized, which may not be in lowest terms
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct FractionParts {
+    numerator: u16,
+    denominator: u16,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<FractionParts> for Fraction {
+    type Error = &'static str;
+
+    fn try_from(parts: FractionParts) -> Result<Self, Self::Error> {
+        Self::new(parts.numerator, parts.denominator).ok_or("the denominator must not be zero")
+    }
+}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (u32::from(self.numerator) * u32::from(other.denominator))
+            .cmp(&(u32::from(other.numerator) * u32::from(self.denominator)))
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let whole = self.whole_part();
+        let remainder = self.numerator % self.denominator;
+        match (whole, remainder) {
+            (_, 0) => write!(f, "{whole}"),
+            (0, _) => write!(f, "{remainder}/{}", self.denominator),
+            _ => write!(f, "{whole} {remainder}/{}", self.denominator),
+        }
+    }
+}
+
+/// The greatest common divisor of two numbers
+fn gcd(mut a: u16, mut b: u16) -> u16 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fraction() {
+        let half = Fraction::new(2, 4).unwrap();
+        assert_eq!(half, Fraction::new(1, 2).unwrap());
+        assert_eq!((half.numerator(), half.denominator()), (1, 2));
+        assert_eq!(Fraction::new(1, 0), None);
+
+        let mixed = Fraction::from_mixed(2, 1, 2).unwrap();
+        assert!((mixed.to_f32() - 2.5).abs() < f32::EPSILON);
+        assert_eq!(mixed.whole_part(), 2);
+        assert_eq!(mixed.to_string(), "2 1/2");
+        assert_eq!(Fraction::new(1, 16).unwrap().to_string(), "1/16");
+        assert_eq!(Fraction::from(10).to_string(), "10");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() {
+        let half: Fraction = serde_json::from_str(r#"{"numerator":2,"denominator":4}"#).unwrap();
+        assert_eq!(half, Fraction::new(1, 2).unwrap());
+        assert!(serde_json::from_str::<Fraction>(r#"{"numerator":1,"denominator":0}"#).is_err());
+    }
+
+    #[test]
+    fn test_ordering() {
+        let quarter = Fraction::new(1, 4).unwrap();
+        let third = Fraction::new(1, 3).unwrap();
+        assert!(quarter < third);
+        assert!(Fraction::from(1) > third);
+        assert_eq!(
+            Fraction::new(3, 6)
+                .unwrap()
+                .cmp(&Fraction::new(1, 2).unwrap()),
+            Ordering::Equal
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{
+    CloudLayer, CloudType, Data, Metar, Weather, WeatherCondition, WeatherIntensity, Wind,
+    WindSpeed,
+};
+
+/// Visibility below this many metres is considered low
+const LOW_VISIBILITY_METRES: f32 = 1500.;
+/// Gusts at or above this many knots are considered strong
+const STRONG_GUST_KNOTS: f32 = 25.;
+/// Mean winds at or above this many knots are considered strong
+const STRONG_WIND_KNOTS: f32 = 34.;
+/// Icing is possible in visible moisture between these temperatures, in degrees Celsius
+const ICING_TEMPERATURES: std::ops::RangeInclusive<i32> = -20..=2;
+
+/// A hazard to aviation identified from a METAR
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Hazard {
+    /// A thunderstorm at or in the vicinity of the station (TS)
+    Thunderstorm,
+    /// Cumulonimbus or towering cumulus cloud, including in the vicinity
+    Convection,
+    /// Freezing rain, drizzle or unknown precipitation (FZRA, FZDZ, FZUP)
+    FreezingPrecipitation,
+    /// Freezing fog (FZFG)
+    FreezingFog,
+    /// Icing conditions: freezing weather, or precipitation, fog or mist with
+    /// a temperature between -20°C and +2°C
+    Icing,
+    /// Hail or small hail (GR, GS)
+    Hail,
+    /// Heavy precipitation
+    HeavyPrecipitation,
+    /// Volcanic ash (VA)
+    VolcanicAsh,
+    /// A sandstorm (SS)
+    Sandstorm,
+    /// A duststorm (DS)
+    Duststorm,
+    /// A squall (SQ)
+    Squall,
+    /// A funnel cloud, tornado or waterspout (FC)
+    FunnelCloud,
+    /// Obscuration by fog, smoke, sand or widespread dust (FG, FU, SA, DU)
+    Obscuration,
+    /// Visibility below 1500 metres
+    LowVisibility,
+    /// Mean wind of 34 knots or more
+    StrongWind,
+    /// Gusts of 25 knots or more
+    StrongGusts,
+    /// Windshear has been reported
+    Windshear,
+}
+
+impl Hazard {
+    /// Identify the hazards present in a METAR. See [`Metar::hazards`].
+    pub(crate) fn identify(metar: &Metar) -> Vec<Self> {
+        let mut hazards = vec![];
+
+        for wx in metar.current_weather() {
+            if !wx.is_in_vicinity() {
+                hazards.extend(weather_hazards(wx));
+            } else if wx.is_thunderstorm() {
+                // Weather in the vicinity is only a hazard if it is a thunderstorm
+                hazards.push(Hazard::Thunderstorm);
+            }
+        }
+
+        // Icing from visible moisture
+        if let Data::Known(temperature) = metar.temperature {
+            let visible_moisture = metar.current_weather().any(|wx| {
+                !wx.is_in_vicinity()
+                    && wx.phenomena.iter().any(|c| {
+                        matches!(
+                            c,
+                            WeatherCondition::Rain
+                                | WeatherCondition::Drizzle
+                                | WeatherCondition::Snow
+                                | WeatherCondition::SnowGrains
+                                | WeatherCondition::UnknownPrecipitation
+                                | WeatherCondition::Fog
+                                | WeatherCondition::Mist
+                        )
+                    })
+            });
+            if visible_moisture && ICING_TEMPERATURES.contains(&temperature) {
+                hazards.push(Hazard::Icing);
+            }
+        }
+
+        // Convective cloud
+        let convective = |kind: &Data<CloudType>| {
+            matches!(
+                kind,
+                Data::Known(CloudType::Cumulonimbus | CloudType::ToweringCumulus)
+            )
+        };
+        if metar.clouds.layers().iter().any(CloudLayer::is_convective)
+            || metar
+                .clouds_in_vicinity
+                .iter()
+                .any(|(_, kind)| convective(kind))
+        {
+            hazards.push(Hazard::Convection);
+        }
+
+        // Visibility
+        if let Data::Known(visibility) = metar.visibility {
+            if visibility.is_below(LOW_VISIBILITY_METRES) {
+                hazards.push(Hazard::LowVisibility);
+            }
+        }
+
+        // Wind
+        if let Wind::Present { speed, .. } = metar.wind {
+            if speed == WindSpeed::Greater
+                || speed.speed_knots().is_some_and(|s| s >= STRONG_WIND_KNOTS)
+            {
+                hazards.push(Hazard::StrongWind);
+            }
+            if speed.gust_knots().is_some_and(|g| g >= STRONG_GUST_KNOTS) {
+                hazards.push(Hazard::StrongGusts);
+            }
+        }
+
+        if metar.windshear_warnings.is_some() {
+            hazards.push(Hazard::Windshear);
+        }
+
+        hazards.sort();
+        hazards.dedup();
+        hazards
+    }
+}
+
+/// Determine the hazards described by a single weather group
+fn weather_hazards(wx: &Weather) -> Vec<Hazard> {
+    let mut hazards = vec![];
+    let has = |condition| wx.has_condition(condition);
+
+    if wx.is_thunderstorm() {
+        hazards.push(Hazard::Thunderstorm);
+    }
+    if wx.is_freezing() {
+        if has(WeatherCondition::Rain)
+            || has(WeatherCondition::Drizzle)
+            || has(WeatherCondition::UnknownPrecipitation)
+        {
+            hazards.push(Hazard::FreezingPrecipitation);
+            hazards.push(Hazard::Icing);
+        }
+        if has(WeatherCondition::Fog) {
+            hazards.push(Hazard::FreezingFog);
+            hazards.push(Hazard::Icing);
+        }
+    }
+    if has(WeatherCondition::Hail) || has(WeatherCondition::SnowPelletsOrSmallHail) {
+        hazards.push(Hazard::Hail);
+    }
+    if wx.is_precipitation() && wx.intensity == WeatherIntensity::Heavy {
+        hazards.push(Hazard::HeavyPrecipitation);
+    }
+    if has(WeatherCondition::VolcanicAsh) {
+        hazards.push(Hazard::VolcanicAsh);
+    }
+    if has(WeatherCondition::Sandstorm) {
+        hazards.push(Hazard::Sandstorm);
+    }
+    if has(WeatherCondition::Duststorm) {
+        hazards.push(Hazard::Duststorm);
+    }
+    if has(WeatherCondition::Squall) {
+        hazards.push(Hazard::Squall);
+    }
+    if has(WeatherCondition::FunnelCloud) {
+        hazards.push(Hazard::FunnelCloud);
+    }
+    if has(WeatherCondition::Fog)
+        || has(WeatherCondition::Smoke)
+        || has(WeatherCondition::Sand)
+        || has(WeatherCondition::WidespreadDust)
+    {
+        hazards.push(Hazard::Obscuration);
+    }
+
+    hazards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thunderstorm_hazards() {
+        let metar = Metar::parse(
+            "ZGSZ 061900Z 13005MPS 9999 -TSRA FEW015 FEW026CB BKN030 26/25 Q1002 RESHRA",
+        )
+        .unwrap();
+        assert_eq!(
+            metar.hazards(),
+            vec![Hazard::Thunderstorm, Hazard::Convection]
+        );
+    }
+
+    #[test]
+    fn test_icing_hazards() {
+        let metar =
+            Metar::parse("EGPC 241950Z 31015G28KT 1200 FZFG VV002 M01/M02 Q1002 WS R24").unwrap();
+        assert_eq!(
+            metar.hazards(),
+            vec![
+                Hazard::FreezingFog,
+                Hazard::Icing,
+                Hazard::Obscuration,
+                Hazard::LowVisibility,
+                Hazard::StrongGusts,
+                Hazard::Windshear,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_vicinity_hazards() {
+        let metar = Metar::parse("EGLL 121150Z 24005KT 9999 VCFG VCSH FEW010 10/09 Q1020").unwrap();
+        assert!(metar.hazards().is_empty());
+
+        let metar = Metar::parse("EGLL 121150Z 24005KT 9999 VCTS FEW010 10/09 Q1020").unwrap();
+        assert_eq!(metar.hazards(), vec![Hazard::Thunderstorm]);
+    }
+
+    #[test]
+    fn test_no_hazards() {
+        let metar = Metar::parse("EGHI 062050Z 31006KT 270V340 CAVOK 13/07 Q1017").unwrap();
+        assert!(metar.hazards().is_empty());
+    }
+}
@@ -0,0 +1,489 @@
+use std::{fmt, str::FromStr};
+
+use chumsky::prelude::*;
+
+use crate::{traits::Parsable, ErrorVariant, OwnedMetarError};
+
+/// A four character ICAO location indicator, identifying the station making a report
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub struct Icao(pub(crate) [u8; 4]);
+
+impl Icao {
+    /// Get this identifier as a string
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // Only ever constructed from ASCII
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// Get the ICAO region this station is in, from the first letter of the identifier.
+    ///
+    /// Returns [`None`] if the identifier does not start with a letter
+    /// assigned to a region.
+    #[must_use]
+    pub fn region(&self) -> Option<IcaoRegion> {
+        Some(match self.0[0] {
+            b'A' => IcaoRegion::WesternSouthPacific,
+            b'B' => IcaoRegion::GreenlandIcelandAndKosovo,
+            b'C' => IcaoRegion::Canada,
+            b'D' => IcaoRegion::EasternWestAfrica,
+            b'E' => IcaoRegion::NorthernEurope,
+            b'F' => IcaoRegion::CentralAndSouthernAfrica,
+            b'G' => IcaoRegion::WesternWestAfrica,
+            b'H' => IcaoRegion::EastAfrica,
+            b'K' => IcaoRegion::ContiguousUnitedStates,
+            b'L' => IcaoRegion::SouthernEurope,
+            b'M' => IcaoRegion::CentralAmerica,
+            b'N' => IcaoRegion::SouthPacific,
+            b'O' => IcaoRegion::MiddleEast,
+            b'P' => IcaoRegion::NorthernPacific,
+            b'R' => IcaoRegion::EastAsia,
+            b'S' => IcaoRegion::SouthAmerica,
+            b'T' => IcaoRegion::Caribbean,
+            b'U' => IcaoRegion::RussiaAndFormerSovietStates,
+            b'V' => IcaoRegion::SouthAsia,
+            b'W' => IcaoRegion::MaritimeSoutheastAsia,
+            b'Y' => IcaoRegion::Australia,
+            b'Z' => IcaoRegion::ChinaMongoliaAndNorthKorea,
+            _ => return None,
+        })
+    }
+
+    /// Get the country or territory this station is in, decoded from the
+    /// prefix of the identifier, for example `EG` is the United Kingdom.
+    ///
+    /// Returns [`None`] if the prefix is not known.
+    #[must_use]
+    pub fn country(&self) -> Option<&'static str> {
+        let code = self.as_str();
+        COUNTRY_PREFIXES
+            .iter()
+            .filter(|(prefix, _)| code.starts_with(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, country)| *country)
+    }
+}
+
+impl fmt::Display for Icao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Debug for Icao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Icao").field(&self.as_str()).finish()
+    }
+}
+
+impl AsRef<str> for Icao {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for Icao {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Icao {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl FromStr for Icao {
+    type Err = OwnedMetarError;
+
+    /// Parse an ICAO identifier, which must be four letters or digits. Lower
+    /// case letters are accepted and converted to upper case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match <[u8; 4]>::try_from(s.as_bytes()) {
+            Ok(code) if code.iter().all(u8::is_ascii_alphanumeric) => {
+                Ok(Icao(code.map(|c| c.to_ascii_uppercase())))
+            }
+            _ => Err(OwnedMetarError {
+                string: s.to_string(),
+                start: 0,
+                end: s.len(),
+                variant: ErrorVariant::InvalidIcao,
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for Icao {
+    type Error = OwnedMetarError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Icao> for String {
+    fn from(value: Icao) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl Parsable for Icao {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789")
+            .repeated()
+            .exactly(4)
+            .collect_exactly::<[char; 4]>()
+            .map(|code| Icao(code.map(|c| u8::try_from(c).unwrap_or_default())))
+    }
+}
+
+/// The ICAO region a station is in, given by the first letter of its identifier
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IcaoRegion {
+    /// Western South Pacific (A)
+    WesternSouthPacific,
+    /// Greenland, Iceland and Kosovo (B)
+    GreenlandIcelandAndKosovo,
+    /// Canada (C)
+    Canada,
+    /// Eastern parts of West Africa and the Maghreb (D)
+    EasternWestAfrica,
+    /// Northern Europe (E)
+    NorthernEurope,
+    /// Central and Southern Africa, and the Indian Ocean (F)
+    CentralAndSouthernAfrica,
+    /// Western parts of West Africa and the Maghreb (G)
+    WesternWestAfrica,
+    /// East and North-East Africa (H)
+    EastAfrica,
+    /// The contiguous United States (K)
+    ContiguousUnitedStates,
+    /// Southern Europe, Israel and Turkey (L)
+    SouthernEurope,
+    /// Central America, Mexico and the northern Caribbean (M)
+    CentralAmerica,
+    /// South Pacific (N)
+    SouthPacific,
+    /// Middle East and Pakistan (O)
+    MiddleEast,
+    /// Northern Pacific, including Alaska and Hawaii (P)
+    NorthernPacific,
+    /// East Asia (R)
+    EastAsia,
+    /// South America (S)
+    SouthAmerica,
+    /// Eastern and southern Caribbean (T)
+    Caribbean,
+    /// Russia and post-Soviet states (U)
+    RussiaAndFormerSovietStates,
+    /// South Asia and mainland Southeast Asia (V)
+    SouthAsia,
+    /// Maritime Southeast Asia (W)
+    MaritimeSoutheastAsia,
+    /// Australia (Y)
+    Australia,
+    /// China, Mongolia and North Korea (Z)
+    ChinaMongoliaAndNorthKorea,
+}
+
+/// Identifier prefixes and the country or territory they are allocated to.
+/// The longest matching prefix applies.
+const COUNTRY_PREFIXES: &[(&str, &str)] = &[
+    ("AG", "Solomon Islands"),
+    ("AN", "Nauru"),
+    ("AY", "Papua New Guinea"),
+    ("BG", "Greenland"),
+    ("BI", "Iceland"),
+    ("BK", "Kosovo"),
+    ("C", "Canada"),
+    ("DA", "Algeria"),
+    ("DB", "Benin"),
+    ("DF", "Burkina Faso"),
+    ("DG", "Ghana"),
+    ("DI", "Côte d'Ivoire"),
+    ("DN", "Nigeria"),
+    ("DR", "Niger"),
+    ("DT", "Tunisia"),
+    ("DX", "Togo"),
+    ("EB", "Belgium"),
+    ("ED", "Germany"),
+    ("EE", "Estonia"),
+    ("EF", "Finland"),
+    ("EG", "United Kingdom"),
+    ("EH", "Netherlands"),
+    ("EI", "Ireland"),
+    ("EK", "Denmark"),
+    ("EL", "Luxembourg"),
+    ("EN", "Norway"),
+    ("EP", "Poland"),
+    ("ES", "Sweden"),
+    ("ET", "Germany"),
+    ("EV", "Latvia"),
+    ("EY", "Lithuania"),
+    ("FA", "South Africa"),
+    ("FB", "Botswana"),
+    ("FC", "Republic of the Congo"),
+    ("FD", "Eswatini"),
+    ("FE", "Central African Republic"),
+    ("FG", "Equatorial Guinea"),
+    ("FH", "Saint Helena, Ascension and Tristan da Cunha"),
+    ("FI", "Mauritius"),
+    ("FJ", "British Indian Ocean Territory"),
+    ("FK", "Cameroon"),
+    ("FL", "Zambia"),
+    ("FMC", "Comoros"),
+    ("FME", "Réunion"),
+    ("FMM", "Madagascar"),
+    ("FMN", "Madagascar"),
+    ("FMS", "Madagascar"),
+    ("FN", "Angola"),
+    ("FO", "Gabon"),
+    ("FP", "São Tomé and Príncipe"),
+    ("FQ", "Mozambique"),
+    ("FS", "Seychelles"),
+    ("FT", "Chad"),
+    ("FV", "Zimbabwe"),
+    ("FW", "Malawi"),
+    ("FX", "Lesotho"),
+    ("FY", "Namibia"),
+    ("FZ", "Democratic Republic of the Congo"),
+    ("GA", "Mali"),
+    ("GB", "The Gambia"),
+    ("GC", "Spain"),
+    ("GE", "Spain"),
+    ("GF", "Sierra Leone"),
+    ("GG", "Guinea-Bissau"),
+    ("GL", "Liberia"),
+    ("GM", "Morocco"),
+    ("GO", "Senegal"),
+    ("GQ", "Mauritania"),
+    ("GS", "Western Sahara"),
+    ("GU", "Guinea"),
+    ("GV", "Cape Verde"),
+    ("HA", "Ethiopia"),
+    ("HB", "Burundi"),
+    ("HC", "Somalia"),
+    ("HD", "Djibouti"),
+    ("HE", "Egypt"),
+    ("HH", "Eritrea"),
+    ("HJ", "South Sudan"),
+    ("HK", "Kenya"),
+    ("HL", "Libya"),
+    ("HR", "Rwanda"),
+    ("HS", "Sudan"),
+    ("HT", "Tanzania"),
+    ("HU", "Uganda"),
+    ("K", "United States"),
+    ("LA", "Albania"),
+    ("LB", "Bulgaria"),
+    ("LC", "Cyprus"),
+    ("LD", "Croatia"),
+    ("LE", "Spain"),
+    ("LF", "France"),
+    ("LG", "Greece"),
+    ("LH", "Hungary"),
+    ("LI", "Italy"),
+    ("LJ", "Slovenia"),
+    ("LK", "Czech Republic"),
+    ("LL", "Israel"),
+    ("LM", "Malta"),
+    ("LN", "Monaco"),
+    ("LO", "Austria"),
+    ("LP", "Portugal"),
+    ("LQ", "Bosnia and Herzegovina"),
+    ("LR", "Romania"),
+    ("LS", "Switzerland"),
+    ("LT", "Turkey"),
+    ("LU", "Moldova"),
+    ("LW", "North Macedonia"),
+    ("LX", "Gibraltar"),
+    ("LY", "Serbia"),
+    ("LYP", "Montenegro"),
+    ("LYT", "Montenegro"),
+    ("LZ", "Slovakia"),
+    ("MB", "Turks and Caicos Islands"),
+    ("MD", "Dominican Republic"),
+    ("MG", "Guatemala"),
+    ("MH", "Honduras"),
+    ("MK", "Jamaica"),
+    ("MM", "Mexico"),
+    ("MN", "Nicaragua"),
+    ("MP", "Panama"),
+    ("MR", "Costa Rica"),
+    ("MS", "El Salvador"),
+    ("MT", "Haiti"),
+    ("MU", "Cuba"),
+    ("MW", "Cayman Islands"),
+    ("MY", "Bahamas"),
+    ("MZ", "Belize"),
+    ("NC", "Cook Islands"),
+    ("NFF", "Fiji"),
+    ("NFN", "Fiji"),
+    ("NFT", "Tonga"),
+    ("NG", "Kiribati"),
+    ("NGF", "Tuvalu"),
+    ("NI", "Niue"),
+    ("NL", "Wallis and Futuna"),
+    ("NSF", "Samoa"),
+    ("NST", "American Samoa"),
+    ("NT", "French Polynesia"),
+    ("NV", "Vanuatu"),
+    ("NW", "New Caledonia"),
+    ("NZ", "New Zealand"),
+    ("OA", "Afghanistan"),
+    ("OB", "Bahrain"),
+    ("OE", "Saudi Arabia"),
+    ("OI", "Iran"),
+    ("OJ", "Jordan"),
+    ("OK", "Kuwait"),
+    ("OL", "Lebanon"),
+    ("OM", "United Arab Emirates"),
+    ("OO", "Oman"),
+    ("OP", "Pakistan"),
+    ("OR", "Iraq"),
+    ("OS", "Syria"),
+    ("OT", "Qatar"),
+    ("OY", "Yemen"),
+    ("PA", "United States"),
+    ("PF", "United States"),
+    ("PG", "Guam"),
+    ("PGS", "Northern Mariana Islands"),
+    ("PGR", "Northern Mariana Islands"),
+    ("PH", "United States"),
+    ("PK", "Marshall Islands"),
+    ("PL", "Kiribati"),
+    ("PO", "United States"),
+    ("PP", "United States"),
+    ("PT", "Federated States of Micronesia"),
+    ("PTR", "Palau"),
+    ("PW", "United States"),
+    ("RC", "Taiwan"),
+    ("RJ", "Japan"),
+    ("RK", "South Korea"),
+    ("RO", "Japan"),
+    ("RP", "Philippines"),
+    ("SA", "Argentina"),
+    ("SB", "Brazil"),
+    ("SC", "Chile"),
+    ("SD", "Brazil"),
+    ("SE", "Ecuador"),
+    ("SF", "Falkland Islands"),
+    ("SG", "Paraguay"),
+    ("SI", "Brazil"),
+    ("SJ", "Brazil"),
+    ("SK", "Colombia"),
+    ("SL", "Bolivia"),
+    ("SM", "Suriname"),
+    ("SN", "Brazil"),
+    ("SO", "French Guiana"),
+    ("SP", "Peru"),
+    ("SS", "Brazil"),
+    ("SU", "Uruguay"),
+    ("SV", "Venezuela"),
+    ("SW", "Brazil"),
+    ("SY", "Guyana"),
+    ("TA", "Antigua and Barbuda"),
+    ("TB", "Barbados"),
+    ("TD", "Dominica"),
+    ("TF", "France"),
+    ("TG", "Grenada"),
+    ("TI", "United States Virgin Islands"),
+    ("TJ", "Puerto Rico"),
+    ("TK", "Saint Kitts and Nevis"),
+    ("TL", "Saint Lucia"),
+    ("TN", "Netherlands"),
+    ("TQ", "Anguilla"),
+    ("TR", "Montserrat"),
+    ("TT", "Trinidad and Tobago"),
+    ("TU", "British Virgin Islands"),
+    ("TV", "Saint Vincent and the Grenadines"),
+    ("TX", "Bermuda"),
+    ("U", "Russia"),
+    ("UA", "Kazakhstan"),
+    ("UB", "Azerbaijan"),
+    ("UC", "Kyrgyzstan"),
+    ("UD", "Armenia"),
+    ("UG", "Georgia"),
+    ("UK", "Ukraine"),
+    ("UM", "Belarus"),
+    ("UMK", "Russia"),
+    ("UTA", "Turkmenistan"),
+    ("UTD", "Tajikistan"),
+    ("UTN", "Uzbekistan"),
+    ("UTS", "Uzbekistan"),
+    ("UTT", "Uzbekistan"),
+    ("VA", "India"),
+    ("VC", "Sri Lanka"),
+    ("VD", "Cambodia"),
+    ("VE", "India"),
+    ("VG", "Bangladesh"),
+    ("VH", "Hong Kong"),
+    ("VI", "India"),
+    ("VL", "Laos"),
+    ("VM", "Macau"),
+    ("VN", "Nepal"),
+    ("VO", "India"),
+    ("VQ", "Bhutan"),
+    ("VR", "Maldives"),
+    ("VT", "Thailand"),
+    ("VV", "Vietnam"),
+    ("VY", "Myanmar"),
+    ("WA", "Indonesia"),
+    ("WB", "Malaysia"),
+    ("WBS", "Brunei"),
+    ("WI", "Indonesia"),
+    ("WM", "Malaysia"),
+    ("WP", "Timor-Leste"),
+    ("WR", "Indonesia"),
+    ("WS", "Singapore"),
+    ("Y", "Australia"),
+    ("Z", "China"),
+    ("ZK", "North Korea"),
+    ("ZM", "Mongolia"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icao() {
+        let icao = Icao::parse("EGLL").unwrap();
+        assert_eq!(icao, "EGLL");
+        assert_eq!(format!("{icao:?}"), r#"Icao("EGLL")"#);
+        assert_eq!(icao.to_string(), "EGLL");
+        assert_eq!("egll".parse::<Icao>().unwrap(), icao);
+        assert!("EGL".parse::<Icao>().is_err());
+        assert!("EG-L".parse::<Icao>().is_err());
+    }
+
+    #[test]
+    fn test_country() {
+        let country = |code: &str| code.parse::<Icao>().unwrap().country();
+        assert_eq!(country("EGLL"), Some("United Kingdom"));
+        assert_eq!(country("KLAX"), Some("United States"));
+        assert_eq!(country("K2R2"), Some("United States"));
+        assert_eq!(country("ZBAA"), Some("China"));
+        assert_eq!(country("ZMCK"), Some("Mongolia"));
+        assert_eq!(country("UUDD"), Some("Russia"));
+        assert_eq!(country("UMKK"), Some("Russia"));
+        assert_eq!(country("UMMS"), Some("Belarus"));
+        assert_eq!(country("UCFM"), Some("Kyrgyzstan"));
+        assert_eq!(country("XXXX"), None);
+    }
+
+    #[test]
+    fn test_region() {
+        let region = |code: &str| code.parse::<Icao>().unwrap().region();
+        assert_eq!(region("KLAX"), Some(IcaoRegion::ContiguousUnitedStates));
+        assert_eq!(region("EGLL"), Some(IcaoRegion::NorthernEurope));
+        assert_eq!(region("1234"), None);
+    }
+}
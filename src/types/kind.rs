@@ -1,11 +1,199 @@
+use std::{cmp::Ordering, fmt};
+
+use chumsky::prelude::*;
+
+use crate::{
+    parsers::{any_whitespace, end_of_group},
+    traits::Parsable,
+};
+
 /// The kind of METAR produced.
+///
+/// The modifiers are independent, so for example a report can be both
+/// automatic and a correction (`AUTO COR`). A report with none of them set is
+/// a regular METAR.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Kind {
+    /// This METAR was generated automatically without human oversight (AUTO)
+    pub automatic: bool,
+    /// This METAR corrects a previously issued METAR (COR, CCA, CCB, ...)
+    pub correction: Option<Correction>,
+    /// This METAR was issued late, after its scheduled time (RTD)
+    pub delayed: bool,
+    /// This METAR is missing, so no observations are reported (NIL)
+    pub nil: bool,
+}
+
+impl Kind {
+    /// Is this a regular METAR, with no modifiers?
+    #[must_use]
+    pub fn is_normal(self) -> bool {
+        self == Self::default()
+    }
+
+    /// Does this report supersede another report of the `other` kind for the
+    /// same station and time?
+    ///
+    /// A correction supersedes a report that is not a correction, and a
+    /// sequenced correction supersedes one with an earlier sequence letter.
+    /// An unsequenced correction (`COR`) cannot be ordered against another
+    /// correction, so neither supersedes the other.
+    #[must_use]
+    pub fn supersedes(self, other: Kind) -> bool {
+        match (self.correction, other.correction) {
+            (Some(_), None) => true,
+            (Some(this), Some(other)) => this.partial_cmp(&other) == Some(Ordering::Greater),
+            (None, _) => false,
+        }
+    }
+
+    /// Apply a single modifier group to this kind
+    fn with(mut self, modifier: Modifier) -> Self {
+        match modifier {
+            Modifier::Automatic => self.automatic = true,
+            Modifier::Correction(correction) => {
+                // Keep the most specific correction if both forms are given
+                if self.correction.is_none_or(|c| c == Correction::Unsequenced) {
+                    self.correction = Some(correction);
+                }
+            }
+            Modifier::Delayed => self.delayed = true,
+            Modifier::Nil => self.nil = true,
+        }
+        self
+    }
+
+    /// Combine the modifiers of two kinds, such as those given before the
+    /// station and after the time
+    #[must_use]
+    pub(crate) fn combine(self, other: Kind) -> Self {
+        let mut kind = Self {
+            automatic: self.automatic || other.automatic,
+            correction: self.correction,
+            delayed: self.delayed || other.delayed,
+            nil: self.nil || other.nil,
+        };
+        if let Some(correction) = other.correction {
+            kind = kind.with(Modifier::Correction(correction));
+        }
+        kind
+    }
+}
+
+impl Parsable for Kind {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        choice((
+            just("AUTO").map(|_| Modifier::Automatic),
+            just("COR").map(|_| Modifier::Correction(Correction::Unsequenced)),
+            just("CC")
+                .ignore_then(one_of('A'..='Z'))
+                .map(|letter| Modifier::Correction(Correction::Sequence(letter))),
+            just("RTD").map(|_| Modifier::Delayed),
+            just("NIL").map(|_| Modifier::Nil),
+        ))
+        .then_ignore(end_of_group())
+        .then_ignore(any_whitespace())
+        .repeated()
+        .collect::<Vec<_>>()
+        .map(|modifiers| modifiers.into_iter().fold(Kind::default(), Kind::with))
+    }
+}
+
+/// A correction to a previously issued METAR
 #[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum Kind {
-    /// This is a regular METAR.
-    Normal,
-    /// This METAR was generated automatically without human oversight
+pub enum Correction {
+    /// A correction without a sequence letter (COR)
+    Unsequenced,
+    /// A correction with a sequence letter, where the first correction is `A`
+    /// (CCA), the second `B` (CCB) and so on
+    Sequence(char),
+}
+
+impl Correction {
+    /// The position of this correction in its sequence, starting from 1 for
+    /// `CCA`, or [`None`] if it is unsequenced
+    #[must_use]
+    pub fn sequence_number(self) -> Option<u8> {
+        match self {
+            Self::Unsequenced => None,
+            Self::Sequence(letter) => u8::try_from(letter)
+                .ok()
+                .filter(u8::is_ascii_uppercase)
+                .map(|letter| letter - b'A' + 1),
+        }
+    }
+}
+
+/// Sequenced corrections are ordered by their letter, so a later correction
+/// compares greater. Unsequenced corrections only compare equal to each other.
+impl PartialOrd for Correction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Unsequenced, Self::Unsequenced) => Some(Ordering::Equal),
+            (Self::Sequence(a), Self::Sequence(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Correction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsequenced => write!(f, "COR"),
+            Self::Sequence(letter) => write!(f, "CC{letter}"),
+        }
+    }
+}
+
+/// A single report modifier group
+#[derive(Copy, Clone)]
+enum Modifier {
     Automatic,
-    /// This METAR corrects a previously issued METAR
-    Correction,
+    Correction(Correction),
+    Delayed,
+    Nil,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind() {
+        assert!(Kind::parse("").unwrap().is_normal());
+        let kind = Kind::parse("AUTO COR ").unwrap();
+        assert!(kind.automatic);
+        assert_eq!(kind.correction, Some(Correction::Unsequenced));
+
+        let kind = Kind::parse("CCB RTD").unwrap();
+        assert_eq!(kind.correction, Some(Correction::Sequence('B')));
+        assert_eq!(
+            kind.correction.and_then(Correction::sequence_number),
+            Some(2)
+        );
+        assert!(kind.delayed);
+        assert!(!kind.automatic);
+
+        assert!(Kind::parse("NIL").unwrap().nil);
+        assert!(Kind::parse("CCAB").is_err());
+    }
+
+    #[test]
+    fn test_supersedes() {
+        let original = Kind::default();
+        let cca = Kind::parse("CCA").unwrap();
+        let ccb = Kind::parse("CCB").unwrap();
+        let cor = Kind::parse("COR").unwrap();
+
+        assert!(cca.supersedes(original));
+        assert!(cor.supersedes(original));
+        assert!(ccb.supersedes(cca));
+        assert!(!cca.supersedes(ccb));
+        assert!(!cca.supersedes(cca));
+        assert!(!cor.supersedes(cca));
+        assert!(!original.supersedes(cor));
+        assert_eq!(ccb.correction.unwrap().to_string(), "CCB");
+    }
 }
@@ -1,21 +1,32 @@
+#[cfg(feature = "stations")]
+use crate::Station;
 use crate::{
     parsers::{any_whitespace, some_whitespace, temperature},
     traits::Parsable,
-    CloudLayer, CloudType, Clouds, ColourCode, CompassDirection, Data, Kind, MetarError, Pressure,
-    RunwayCondition, RunwayVisualRange, SeaCondition, Time, Trend, VerticalVisibility, Visibility,
-    Weather, WeatherCondition, Wind, WindDirection, WindSpeed, WindshearWarnings,
+    CloudType, Clouds, ColourCode, ColourState, CompassDirection, Data, Daylight,
+    EffectiveConditions, Hazard, Icao, Kind, MetarError, Pressure, ProjectedConditions,
+    RunwayCondition, RunwayVisualRange, SeaCondition, Staleness, StalenessThresholds, Time, Trend,
+    TrendVerification, VerticalVisibility, Visibility, Weather, WeatherCondition,
+    WeatherDescriptor, WeatherIntensity, Wind, WindDirection, WindSpeed, WindshearWarnings,
 };
+use chrono::{DateTime, FixedOffset, TimeDelta, TimeZone, Utc};
 use chumsky::prelude::*;
 
-#[derive(PartialEq, Clone, Debug)]
+/// How far in the future a report may be timestamped, beyond any future
+/// tolerance, and still be resolved into the current month when finding its
+/// age. A report further in the future is assumed to be from the month before.
+const CLOCK_SKEW_TOLERANCE: TimeDelta = TimeDelta::hours(1);
+
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A complete METAR
 pub struct Metar {
     /// The station making the METAR measurement
-    pub station: String,
+    pub station: Icao,
     /// The measurement time
     pub time: Time,
-    /// The kind of METAR, i.e. Normal, Automatic or Correction
+    /// The kind of METAR, such as whether it is automatic, a correction or
+    /// missing (NIL)
     pub kind: Kind,
     /// The current wind information
     pub wind: Wind,
@@ -27,10 +38,8 @@ pub struct Metar {
     pub reduced_directional_visibility: Vec<(Option<CompassDirection>, Data<Visibility>)>,
     /// Specific visual ranges for runways
     pub rvr: Vec<RunwayVisualRange>,
-    /// The current clouds
+    /// The current state of the sky, including any cloud layers
     pub clouds: Clouds,
-    /// The current clouds
-    pub cloud_layers: Vec<CloudLayer>,
     /// The current vertical visibility, in feet
     pub vert_visibility: Option<VerticalVisibility>,
     /// The current weather conditions
@@ -41,10 +50,12 @@ pub struct Metar {
     pub dewpoint: Data<i32>,
     /// The current air pressure
     pub pressure: Pressure,
-    /// Military airport colour code
-    pub colour_code: Option<ColourCode>,
-    /// Additional recent weather conditions
-    pub recent_weather: Vec<Data<Vec<WeatherCondition>>>,
+    /// Military airport colour state
+    pub colour_code: Option<ColourState>,
+    /// Additional recent weather conditions, each with an intensity of
+    /// [`WeatherIntensity::Recent`]. Recent weather that could not be observed
+    /// by an automatic station (`RE//`) is [`Data::Unknown`].
+    pub recent_weather: Vec<Data<Weather>>,
     /// Windshear warnings
     pub windshear_warnings: Option<WindshearWarnings>,
     /// Sea surface condition
@@ -62,32 +73,15 @@ pub struct Metar {
 impl Parsable for Metar {
     #[allow(clippy::too_many_lines)]
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<MetarError<'src>>> {
-        fn method<'src>() -> impl Parser<'src, &'src str, Kind, extra::Err<crate::MetarError<'src>>>
-        {
-            choice((
-                just("AUTO")
-                    .map(|_| Kind::Automatic)
-                    .then_ignore(some_whitespace()),
-                just("COR")
-                    .map(|_| Kind::Correction)
-                    .then_ignore(some_whitespace()),
-                just("CCA")
-                    .map(|_| Kind::Correction)
-                    .then_ignore(some_whitespace()),
-                empty().map(|()| Kind::Normal),
-            ))
-        }
-        let station = regex("[A-Z0-9]{4}");
-
         group((
             just("METAR")
                 .then_ignore(some_whitespace())
                 .map(|_| ())
                 .or(empty()),
-            method(),
-            station.then_ignore(some_whitespace()),
+            Kind::parser(),
+            Icao::parser().then_ignore(some_whitespace()),
             Time::parser().then_ignore(some_whitespace()),
-            method(),
+            Kind::parser(),
             choice((
                 Wind::parser(),
                 empty().map(|()| Wind::Present {
@@ -111,34 +105,20 @@ impl Parsable for Metar {
                 .separated_by(some_whitespace())
                 .allow_trailing()
                 .collect::<Vec<_>>(),
-            choice((
-                just("SKC")
-                    .map(|_| (Data::Known(vec![]), None, Clouds::NoCloudDetected, vec![]))
-                    .then_ignore(some_whitespace()),
-                just("CLR")
-                    .map(|_| (Data::Known(vec![]), None, Clouds::NoCloudDetected, vec![]))
-                    .then_ignore(some_whitespace()),
-                group((
-                    Data::parser_inline(
-                        2,
-                        Weather::parser()
-                            .separated_by(some_whitespace())
-                            .collect::<Vec<_>>(),
-                    )
-                    .then_ignore(some_whitespace())
-                    .or(empty().map(|()| Data::Known(vec![]))),
-                    VerticalVisibility::parser()
-                        .map(Some)
-                        .then_ignore(some_whitespace())
-                        .or(empty().map(|()| None)),
-                    Clouds::parser(),
-                    CloudLayer::parser()
+            group((
+                Data::parser_inline(
+                    2,
+                    Weather::parser()
                         .separated_by(some_whitespace())
-                        .allow_trailing()
                         .collect::<Vec<_>>(),
-                ))
-                .map(|(wx, vvis, clouds, layers)| (wx, vvis, clouds, layers)),
-                empty().map(|()| (Data::Known(vec![]), None, Clouds::NoCloudDetected, vec![])),
+                )
+                .then_ignore(some_whitespace())
+                .or(empty().map(|()| Data::Known(vec![]))),
+                VerticalVisibility::parser()
+                    .map(Some)
+                    .then_ignore(some_whitespace())
+                    .or(empty().map(|()| None)),
+                Clouds::parser(),
             )),
             group((
                 Data::parser_inline(2, temperature()),
@@ -151,23 +131,20 @@ impl Parsable for Metar {
             Pressure::parser()
                 .then_ignore(some_whitespace())
                 .or(empty().map(|()| Pressure::Hectopascals(Data::Unknown))),
-            ColourCode::parser()
+            ColourState::parser()
                 .map(Some)
                 .then_ignore(some_whitespace())
                 .or(empty().map(|()| None)),
             choice((
-                just("RE")
-                    .then(Data::parser_inline(
-                        2,
-                        WeatherCondition::parser()
-                            .repeated()
-                            .at_least(1)
-                            .collect::<Vec<_>>(),
-                    ))
-                    .map(|(_, wx)| wx)
-                    .separated_by(some_whitespace())
-                    .collect::<Vec<_>>()
-                    .then_ignore(some_whitespace()),
+                choice((
+                    just("RE//").map(|_| Data::Unknown),
+                    Weather::parser()
+                        .filter(|wx| wx.intensity == WeatherIntensity::Recent)
+                        .map(Data::Known),
+                ))
+                .separated_by(some_whitespace())
+                .collect::<Vec<_>>()
+                .then_ignore(some_whitespace()),
                 empty().map(|()| vec![]),
             )),
             WindshearWarnings::parser()
@@ -208,7 +185,7 @@ impl Parsable for Metar {
                 visibility,
                 reduced_directional_visibility,
                 rvr,
-                (weather, vert_visibility, clouds, cloud_layers),
+                (weather, vert_visibility, clouds),
                 (temperature, dewpoint),
                 pressure,
                 colour_code,
@@ -223,13 +200,9 @@ impl Parsable for Metar {
                 (),
             )| {
                 Metar {
-                    station: station.to_string(),
+                    station,
                     time,
-                    kind: if early_kind == Kind::Normal {
-                        kind
-                    } else {
-                        early_kind
-                    },
+                    kind: early_kind.combine(kind),
                     wind,
                     visibility,
                     reduced_directional_visibility,
@@ -237,7 +210,6 @@ impl Parsable for Metar {
                     weather,
                     vert_visibility,
                     clouds,
-                    cloud_layers,
                     temperature,
                     dewpoint,
                     pressure,
@@ -271,4 +243,348 @@ impl Metar {
                 .collect::<Vec<_>>()
         })
     }
+
+    /// Does this METAR supersede `other`, being a later correction of a report
+    /// from the same station at the same time? See [`Kind::supersedes`].
+    #[must_use]
+    pub fn supersedes(&self, other: &Metar) -> bool {
+        self.station == other.station && self.time == other.time && self.kind.supersedes(other.kind)
+    }
+
+    /// Iterate over the current weather groups, excluding any recent weather
+    /// groups, if the weather is known.
+    pub(crate) fn current_weather(&self) -> impl Iterator<Item = &Weather> {
+        let weather = match &self.weather {
+            Data::Known(weather) => weather.as_slice(),
+            Data::Unknown => &[],
+        };
+        weather
+            .iter()
+            .filter(|wx| wx.intensity != WeatherIntensity::Recent)
+    }
+
+    /// Does the current weather include the given condition?
+    #[must_use]
+    pub fn has_condition(&self, condition: WeatherCondition) -> bool {
+        self.current_weather().any(|wx| wx.has_condition(condition))
+    }
+
+    /// Does the current weather include the given descriptor?
+    #[must_use]
+    pub fn has_descriptor(&self, descriptor: WeatherDescriptor) -> bool {
+        self.current_weather()
+            .any(|wx| wx.has_descriptor(descriptor))
+    }
+
+    /// Is there a thunderstorm in the current weather?
+    #[must_use]
+    pub fn has_thunderstorm(&self) -> bool {
+        self.current_weather().any(Weather::is_thunderstorm)
+    }
+
+    /// Is there any precipitation in the current weather?
+    #[must_use]
+    pub fn has_precipitation(&self) -> bool {
+        self.current_weather().any(Weather::is_precipitation)
+    }
+
+    /// Is there any obscuration in the current weather?
+    #[must_use]
+    pub fn has_obscuration(&self) -> bool {
+        self.current_weather().any(Weather::is_obscuration)
+    }
+
+    /// Is there any freezing weather currently?
+    #[must_use]
+    pub fn has_freezing_weather(&self) -> bool {
+        self.current_weather().any(Weather::is_freezing)
+    }
+
+    /// Get the strongest intensity of the current weather, ignoring weather
+    /// in the vicinity.
+    #[must_use]
+    pub fn max_intensity(&self) -> Option<WeatherIntensity> {
+        WeatherIntensity::strongest(self.current_weather().map(|wx| wx.intensity))
+    }
+
+    /// Derive the military colour code from the effective visibility and
+    /// cloud base. See [`crate::EffectiveConditions::cloud_base_feet`].
+    ///
+    /// Returns [`None`] if the visibility is unknown.
+    #[must_use]
+    pub fn derive_colour_code(&self) -> Option<ColourCode> {
+        let conditions = self.effective_conditions();
+        let Data::Known(visibility) = conditions.visibility else {
+            return None;
+        };
+
+        Some(ColourCode::from_conditions(
+            visibility.to_metres(),
+            conditions.cloud_base_feet(),
+        ))
+    }
+
+    /// Get the current conditions of this METAR, with CAVOK expanded into the
+    /// conditions it implies. See [`EffectiveConditions`].
+    #[must_use]
+    pub fn effective_conditions(&self) -> EffectiveConditions {
+        EffectiveConditions::expand(
+            self.wind,
+            self.visibility,
+            self.weather.clone(),
+            self.vert_visibility,
+            self.clouds.clone(),
+        )
+    }
+
+    /// Classify the hazards present in this METAR from its weather, clouds,
+    /// visibility, wind, windshear warnings and temperature.
+    ///
+    /// Recent weather is not considered, and weather in the vicinity is only
+    /// considered if it is a thunderstorm. The returned hazards are sorted and
+    /// contain no duplicates.
+    #[must_use]
+    pub fn hazards(&self) -> Vec<Hazard> {
+        Hazard::identify(self)
+    }
+
+    /// Resolve the observation time of this METAR into a full UTC date and
+    /// time, relative to `reference`. See [`crate::Time::resolve`].
+    #[must_use]
+    pub fn observation_time(&self, reference: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.time.resolve(reference)
+    }
+
+    /// The age of this METAR at `now`. This is negative if the report is
+    /// timestamped in the future, by no more than an hour.
+    ///
+    /// The observation time is resolved to the latest time that is not in the
+    /// future, so a report from several weeks ago is old rather than being
+    /// resolved into the next month. See [`crate::Time::resolve_before`].
+    ///
+    /// Returns [`None`] if the observation time cannot be resolved.
+    #[must_use]
+    pub fn age(&self, now: DateTime<Utc>) -> Option<TimeDelta> {
+        self.age_within(now, TimeDelta::zero())
+    }
+
+    /// The age of this METAR at `now`, allowing it to be timestamped in the
+    /// future by up to an hour more than `future_tolerance`
+    fn age_within(&self, now: DateTime<Utc>, future_tolerance: TimeDelta) -> Option<TimeDelta> {
+        self.time
+            .resolve_before(now + future_tolerance + CLOCK_SKEW_TOLERANCE)
+            .map(|observed| now - observed)
+    }
+
+    /// Classify how current this METAR is at `now`, using the given thresholds.
+    ///
+    /// A report timestamped in the future by up to an hour more than the
+    /// future tolerance is [`Staleness::Future`]. One further in the future is
+    /// assumed to be from the month before, as with [`Metar::age`].
+    ///
+    /// Returns [`None`] if the observation time cannot be resolved.
+    #[must_use]
+    pub fn staleness(
+        &self,
+        now: DateTime<Utc>,
+        thresholds: &StalenessThresholds,
+    ) -> Option<Staleness> {
+        self.age_within(now, thresholds.future_tolerance)
+            .map(|age| thresholds.classify(age))
+    }
+
+    /// Is this METAR timestamped in the future at `now`, beyond the tolerance
+    /// of the default [`StalenessThresholds`]?
+    #[must_use]
+    pub fn is_from_future(&self, now: DateTime<Utc>) -> bool {
+        self.staleness(now, &StalenessThresholds::default()) == Some(Staleness::Future)
+    }
+
+    /// Convert the observation time of this METAR into a local time zone,
+    /// resolving it relative to `reference`. See [`crate::Time::resolve`].
+    #[must_use]
+    pub fn local_time<Tz: TimeZone>(
+        &self,
+        reference: DateTime<Utc>,
+        tz: &Tz,
+    ) -> Option<DateTime<Tz>> {
+        self.observation_time(reference)
+            .map(|time| time.with_timezone(tz))
+    }
+
+    /// Convert the observation time of this METAR into local mean time at the
+    /// given longitude, in degrees, which is four minutes ahead of UTC for
+    /// every degree east.
+    #[must_use]
+    pub fn local_mean_time(
+        &self,
+        reference: DateTime<Utc>,
+        longitude: f64,
+    ) -> Option<DateTime<FixedOffset>> {
+        #[allow(clippy::cast_possible_truncation, reason = "bounded by longitude")]
+        let offset = FixedOffset::east_opt((longitude * 240.).round() as i32)?;
+        self.local_time(reference, &offset)
+    }
+
+    /// Determine whether this METAR was observed during the day or night at
+    /// the given location, in degrees, resolving the observation time
+    /// relative to `reference`.
+    #[must_use]
+    pub fn daylight(
+        &self,
+        reference: DateTime<Utc>,
+        latitude: f64,
+        longitude: f64,
+    ) -> Option<Daylight> {
+        self.observation_time(reference)
+            .map(|time| Daylight::at(time, latitude, longitude))
+    }
+
+    /// Look up the reporting station of this METAR in the embedded station table
+    #[cfg(feature = "stations")]
+    #[must_use]
+    pub fn station_info(&self) -> Option<&'static Station> {
+        Station::lookup(self.station)
+    }
+
+    /// Convert the observation time of this METAR into the local time at its
+    /// reporting station, resolving it relative to `reference`. See
+    /// [`Metar::local_time`].
+    ///
+    /// Returns [`None`] if the station is not in the embedded station table
+    /// or the observation time cannot be resolved.
+    #[cfg(feature = "stations")]
+    #[must_use]
+    pub fn station_local_time(&self, reference: DateTime<Utc>) -> Option<DateTime<chrono_tz::Tz>> {
+        let tz = self.station_info()?.timezone;
+        self.local_time(reference, &tz)
+    }
+
+    /// Determine whether this METAR was observed during the day or night at
+    /// its reporting station. See [`Metar::daylight`].
+    ///
+    /// Returns [`None`] if the station is not in the embedded station table
+    /// or the observation time cannot be resolved.
+    #[cfg(feature = "stations")]
+    #[must_use]
+    pub fn station_daylight(&self, reference: DateTime<Utc>) -> Option<Daylight> {
+        let station = self.station_info()?;
+        self.daylight(reference, station.latitude, station.longitude)
+    }
+
+    /// Project the conditions of this METAR forward to the given time, using
+    /// its trend groups. See [`ProjectedConditions`].
+    ///
+    /// A BECMG group takes effect when its change is complete, which is its
+    /// `TL` or `AT` time if given, otherwise its `FM` time, otherwise the
+    /// start of the trend period. A TEMPO group is in effect from its `FM`
+    /// or `AT` time, or the start of the trend period, until its `TL` time or
+    /// the end of the trend period.
+    ///
+    /// Returns [`None`] if the observation time cannot be resolved, or the
+    /// time is not within the two hour trend period.
+    #[must_use]
+    pub fn conditions_at(&self, time: DateTime<Utc>) -> Option<ProjectedConditions> {
+        ProjectedConditions::project(self, time)
+    }
+
+    /// Verify the trend forecast of this METAR against later observations.
+    /// Observations from other stations, or outside the two hour trend
+    /// period, are ignored. The observation time of this METAR is resolved
+    /// relative to `reference`.
+    ///
+    /// An element verifies if the observed value is within the change
+    /// thresholds that a trend should forecast of either the expected or the
+    /// worst case conditions. See [`Metar::conditions_at`].
+    ///
+    /// Returns [`None`] if this METAR has no trend forecast, or its
+    /// observation time cannot be resolved.
+    #[must_use]
+    pub fn verify_trend(
+        &self,
+        later: &[Metar],
+        reference: DateTime<Utc>,
+    ) -> Option<TrendVerification> {
+        TrendVerification::verify(self, later, reference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Correction;
+
+    #[test]
+    fn test_weather_queries() {
+        let metar =
+            Metar::parse("EGHI 071520Z 19013KT 3000 -RADZ BR VCSH BKN006 15/14 Q1012").unwrap();
+        assert!(metar.has_condition(WeatherCondition::Drizzle));
+        assert!(!metar.has_condition(WeatherCondition::Hail));
+        assert!(!metar.has_thunderstorm());
+        assert!(metar.has_descriptor(WeatherDescriptor::Showers));
+        assert!(metar.has_precipitation());
+        assert!(metar.has_obscuration());
+        assert!(!metar.has_freezing_weather());
+        assert_eq!(metar.max_intensity(), Some(WeatherIntensity::Moderate));
+
+        let metar = Metar::parse("EGHI 071520Z 19013KT 3000 +TSRA BKN006CB 15/14 Q1012").unwrap();
+        assert!(metar.has_thunderstorm());
+        assert!(metar.has_descriptor(WeatherDescriptor::Thunderstorm));
+        assert!(!metar.has_descriptor(WeatherDescriptor::Showers));
+        assert_eq!(metar.max_intensity(), Some(WeatherIntensity::Heavy));
+    }
+
+    #[test]
+    fn test_weather_not_observed() {
+        let metar =
+            Metar::parse("EGPC 241950Z AUTO 31015KT 9999 // BKN020 M01/M02 Q1002 RE//").unwrap();
+        assert_eq!(metar.weather, Data::Unknown);
+        assert_eq!(metar.recent_weather, vec![Data::Unknown]);
+
+        let metar =
+            Metar::parse("ZGSZ 061900Z 13005MPS 9999 FEW015 26/25 Q1002 RESHRA RETS").unwrap();
+        assert_eq!(metar.recent_weather.len(), 2);
+        assert!(metar.recent_weather[0].as_ref().unwrap().is_showers());
+    }
+
+    #[test]
+    fn test_kind() {
+        let metar =
+            Metar::parse("METAR COR EGLL 121150Z AUTO 24005KT 9999 NCD 15/10 Q1020").unwrap();
+        assert!(metar.kind.automatic);
+        assert_eq!(metar.kind.correction, Some(Correction::Unsequenced));
+
+        let metar = Metar::parse("UUEE 121200Z RTD 27003MPS 9999 SCT030 12/05 Q1015").unwrap();
+        assert!(metar.kind.delayed);
+        assert_eq!(metar.kind.correction, None);
+
+        let metar = Metar::parse("EGLL 121150Z NIL=").unwrap();
+        assert!(metar.kind.nil);
+        assert_eq!(metar.visibility, Data::Unknown);
+        assert_eq!(metar.temperature, Data::Unknown);
+
+        let original = Metar::parse("EGLL 121150Z 24005KT 9999 NCD 15/10 Q1020").unwrap();
+        let cca = Metar::parse("EGLL 121150Z CCA 24005KT 9999 NCD 15/10 Q1021").unwrap();
+        let ccb = Metar::parse("EGLL 121150Z CCB 24005KT 9999 NCD 15/10 Q1022").unwrap();
+        assert!(cca.supersedes(&original));
+        assert!(ccb.supersedes(&cca));
+        assert!(!cca.supersedes(&ccb));
+        assert!(
+            !ccb.supersedes(&Metar::parse("EGKK 121150Z 24005KT 9999 NCD 15/10 Q1020").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_dedupe() {
+        let reports = [
+            "KLAX 161253Z 08003KT 1/16SM R25L/0800V1000FT FG VV001 12/12 A3007",
+            "KLAX 161253Z 08003KT 2/32SM R25L/0800V1000FT FG VV001 12/12 A3007",
+            "KLAX 161253Z 08003KT 1/8SM R25L/0800V1000FT FG VV001 12/12 A3007",
+        ];
+        let unique = reports
+            .into_iter()
+            .map(|report| Metar::parse(report).unwrap())
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(unique.len(), 2);
+    }
 }
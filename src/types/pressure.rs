@@ -3,14 +3,48 @@ use crate::{traits::Parsable, MetarError};
 use super::Data;
 use chumsky::prelude::*;
 
-#[derive(PartialEq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Measured air pressure
 pub enum Pressure {
     /// Pressure in hectopascals
     Hectopascals(Data<u16>),
-    /// Pressure in inches of mercury (inHg)
-    InchesOfMercury(Data<f32>),
+    /// Pressure in hundredths of an inch of mercury (inHg), for example 2992
+    /// is 29.92 inHg
+    InchesOfMercury(Data<u16>),
+}
+
+/// The number of hectopascals in one inch of mercury
+const HECTOPASCALS_PER_INCH_OF_MERCURY: f32 = 33.863_89;
+
+impl Pressure {
+    /// Get this pressure in hectopascals, converting if necessary.
+    ///
+    /// Returns [`None`] if the pressure is unknown.
+    #[must_use]
+    pub fn hectopascals(self) -> Option<f32> {
+        match self {
+            Self::Hectopascals(Data::Known(hpa)) => Some(f32::from(hpa)),
+            Self::InchesOfMercury(Data::Known(_)) => self
+                .inches_of_mercury()
+                .map(|inhg| inhg * HECTOPASCALS_PER_INCH_OF_MERCURY),
+            _ => None,
+        }
+    }
+
+    /// Get this pressure in inches of mercury, converting if necessary.
+    ///
+    /// Returns [`None`] if the pressure is unknown.
+    #[must_use]
+    pub fn inches_of_mercury(self) -> Option<f32> {
+        match self {
+            Self::Hectopascals(Data::Known(_)) => self
+                .hectopascals()
+                .map(|hpa| hpa / HECTOPASCALS_PER_INCH_OF_MERCURY),
+            Self::InchesOfMercury(Data::Known(hundredths)) => Some(f32::from(hundredths) / 100.),
+            _ => None,
+        }
+    }
 }
 
 impl Parsable for Pressure {
@@ -26,7 +60,7 @@ impl Parsable for Pressure {
                 .map(|(_, d)| Pressure::Hectopascals(d)),
             just("A")
                 .then(Data::parser_inline(4, four_digits))
-                .map(|(_, d)| Pressure::InchesOfMercury(d.map(|v| f32::from(v) / 100.))),
+                .map(|(_, d)| Pressure::InchesOfMercury(d)),
         ))
     }
 }
@@ -47,10 +81,21 @@ mod tests {
     fn valid_mmhg() {
         assert_eq!(
             Pressure::parse("A3012").unwrap(),
-            Pressure::InchesOfMercury(Data::Known(30.12))
+            Pressure::InchesOfMercury(Data::Known(3012))
         );
     }
 
+    #[test]
+    fn convert_pressure() {
+        let inhg = Pressure::parse("A2992").unwrap();
+        assert_eq!(inhg.inches_of_mercury(), Some(29.92));
+        assert!((inhg.hectopascals().unwrap() - 1013.2).abs() < 0.1);
+
+        let hpa = Pressure::parse("Q1013").unwrap();
+        assert!((hpa.inches_of_mercury().unwrap() - 29.91).abs() < 0.01);
+        assert_eq!(Pressure::parse("Q////").unwrap().hectopascals(), None);
+    }
+
     #[test]
     fn valid_unknown_hpa() {
         assert_eq!(
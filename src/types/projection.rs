@@ -0,0 +1,212 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::{EffectiveConditions, Metar, Trend, TrendNewCondition, TrendTime};
+
+/// How long a trend forecast is valid for after the observation
+const TREND_PERIOD: TimeDelta = TimeDelta::hours(2);
+
+/// The conditions forecast at a time within the trend period of a METAR
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectedConditions {
+    /// The conditions expected at the time, with the changes forecast by
+    /// BECMG groups that have completed applied
+    pub expected: EffectiveConditions,
+    /// The worst conditions possible at the time, combining the expected
+    /// conditions with the changes of TEMPO groups that are in effect and
+    /// BECMG groups that are in progress, keeping the worse of each element.
+    /// See [`EffectiveConditions::worst_of`].
+    pub worst_case: EffectiveConditions,
+}
+
+impl ProjectedConditions {
+    /// Project the conditions of a METAR forward to the given time. See
+    /// [`Metar::conditions_at`].
+    pub(crate) fn project(metar: &Metar, time: DateTime<Utc>) -> Option<Self> {
+        let observation = metar.observation_time(time)?;
+        let end_of_period = observation + TREND_PERIOD;
+        if time < observation || time > end_of_period {
+            return None;
+        }
+
+        // Changes that have completed are expected, while those that are
+        // in progress or temporary can only make the worst case worse
+        let mut expected = metar.effective_conditions();
+        let mut possible = vec![];
+        for trend in &metar.trends {
+            match trend {
+                Trend::Becoming(change) => {
+                    let (start, complete) = becoming_window(change, observation);
+                    if time >= complete {
+                        expected = expected.with_trend(change);
+                    } else if time >= start {
+                        possible.push(change);
+                    }
+                }
+                Trend::Temporarily(change) => {
+                    let (start, end) = temporary_window(change, observation, end_of_period);
+                    if time >= start && time < end {
+                        possible.push(change);
+                    }
+                }
+                Trend::NoSignificantChanges | Trend::NoSignificantWeather => (),
+            }
+        }
+        let worst_case = possible
+            .into_iter()
+            .fold(expected.clone(), |worst, change| {
+                worst.worst_of(&expected.with_trend(change))
+            });
+
+        Some(ProjectedConditions {
+            expected,
+            worst_case,
+        })
+    }
+}
+
+/// Find the first time of a kind in a trend, resolved relative to the observation
+fn find_time(
+    change: &TrendNewCondition,
+    observation: DateTime<Utc>,
+    is_kind: impl Fn(&TrendTime) -> bool,
+) -> Option<DateTime<Utc>> {
+    change
+        .time
+        .iter()
+        .find(|time| is_kind(time))
+        .and_then(|time| time.resolve(observation))
+}
+
+/// The times a BECMG change starts and completes
+fn becoming_window(
+    change: &TrendNewCondition,
+    observation: DateTime<Utc>,
+) -> (DateTime<Utc>, DateTime<Utc>) {
+    let from = find_time(change, observation, |time| {
+        matches!(time, TrendTime::From { .. } | TrendTime::At { .. })
+    })
+    .unwrap_or(observation);
+    let complete = find_time(change, observation, |time| {
+        matches!(time, TrendTime::Until { .. } | TrendTime::At { .. })
+    })
+    .unwrap_or(from);
+    (from, complete)
+}
+
+/// The times a TEMPO change starts and ends
+fn temporary_window(
+    change: &TrendNewCondition,
+    observation: DateTime<Utc>,
+    end_of_period: DateTime<Utc>,
+) -> (DateTime<Utc>, DateTime<Utc>) {
+    let from = find_time(change, observation, |time| {
+        matches!(time, TrendTime::From { .. } | TrendTime::At { .. })
+    })
+    .unwrap_or(observation);
+    let until = find_time(change, observation, |time| {
+        matches!(time, TrendTime::Until { .. })
+    })
+    .unwrap_or(end_of_period);
+    (from, until)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Data, Visibility, Wind, WindSpeed};
+
+    fn at(time: &str) -> DateTime<Utc> {
+        format!("2024-06-12T{time}:00Z").parse().unwrap()
+    }
+
+    #[test]
+    fn test_becoming() {
+        let metar = Metar::parse(
+            "EGLL 121150Z 24012KT 4000 -RA BKN012 12/05 Q1020 BECMG FM1230 TL1300 9999 NSW SCT025",
+        )
+        .unwrap();
+
+        let conditions = metar.conditions_at(at("12:00")).unwrap();
+        assert_eq!(conditions.expected, metar.effective_conditions());
+        assert_eq!(conditions.worst_case, conditions.expected);
+
+        // The improvement is in progress, so the worst case is no better
+        let conditions = metar.conditions_at(at("12:45")).unwrap();
+        assert_eq!(conditions.expected.cloud_base_feet(), Some(1200));
+        assert_eq!(conditions.worst_case.cloud_base_feet(), Some(1200));
+        assert_eq!(
+            conditions.worst_case.visibility,
+            Data::Known(Visibility::Metres(4000))
+        );
+        assert_eq!(conditions.worst_case.weather, conditions.expected.weather);
+
+        let conditions = metar.conditions_at(at("13:15")).unwrap();
+        assert_eq!(
+            conditions.expected.visibility,
+            Data::Known(Visibility::TenKilometresOrMore)
+        );
+        assert_eq!(conditions.expected.cloud_base_feet(), Some(2500));
+
+        assert_eq!(metar.conditions_at(at("11:00")), None);
+        assert_eq!(metar.conditions_at(at("14:00")), None);
+    }
+
+    #[test]
+    fn test_temporarily() {
+        let metar = Metar::parse(
+            "EGLL 121150Z 24012KT 9999 FEW030 12/05 Q1020 TEMPO FM1215 TL1315 3000 SHRA BKN008",
+        )
+        .unwrap();
+
+        let conditions = metar.conditions_at(at("12:00")).unwrap();
+        assert_eq!(conditions.worst_case, conditions.expected);
+
+        let conditions = metar.conditions_at(at("12:30")).unwrap();
+        assert_eq!(conditions.expected, metar.effective_conditions());
+        assert_eq!(
+            conditions.worst_case.visibility,
+            Data::Known(Visibility::Metres(3000))
+        );
+        assert_eq!(conditions.worst_case.cloud_base_feet(), Some(800));
+
+        let conditions = metar.conditions_at(at("13:30")).unwrap();
+        assert_eq!(conditions.worst_case, conditions.expected);
+    }
+
+    #[test]
+    fn test_worst_case_is_never_better() {
+        let metar = Metar::parse(
+            "EGLL 121150Z 24012KT 3000 BR BKN008 12/05 Q1020 TEMPO 27025G35KT 9999 NSW SCT015CB",
+        )
+        .unwrap();
+        let conditions = metar.conditions_at(at("12:30")).unwrap();
+        let worst_case = conditions.worst_case;
+        assert_eq!(worst_case.visibility, Data::Known(Visibility::Metres(3000)));
+        assert_eq!(worst_case.weather, conditions.expected.weather);
+        assert_eq!(worst_case.cloud_base_feet(), Some(800));
+        assert!(worst_case.has_convective_cloud());
+        let Wind::Present { speed, .. } = worst_case.wind else {
+            panic!("expected a wind");
+        };
+        assert_eq!(
+            speed,
+            WindSpeed::Knots {
+                speed: Data::Known(25),
+                gusting: Some(Data::Known(35)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_untimed_trends() {
+        let metar =
+            Metar::parse("EGLL 121150Z 24012KT 0800 FG VV002 12/05 Q1020 BECMG CAVOK").unwrap();
+        let conditions = metar.conditions_at(at("12:00")).unwrap();
+        assert_eq!(
+            conditions.expected.visibility,
+            Data::Known(Visibility::TenKilometresOrMore)
+        );
+        assert_eq!(conditions.expected.vert_visibility, None);
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+
+use chumsky::prelude::*;
+
+use crate::{traits::Parsable, ErrorVariant};
+
+/// A runway, as identified in a METAR
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Runway {
+    /// A specific runway
+    Specific {
+        /// The runway number, which is the magnetic heading of the runway in
+        /// tens of degrees
+        number: u8,
+        /// Which of a set of parallel runways this is, if any
+        designator: Option<RunwayDesignator>,
+    },
+    /// All runways of the aerodrome (88)
+    AllRunways,
+    /// No new information is available, so the previous report is repeated (99)
+    RepetitionOfLastMessage,
+}
+
+impl Runway {
+    /// Get the runway number, if this is a specific runway
+    #[must_use]
+    pub fn number(self) -> Option<u8> {
+        match self {
+            Self::Specific { number, .. } => Some(number),
+            _ => None,
+        }
+    }
+
+    /// Get the parallel runway designator, if this is a specific runway and has one
+    #[must_use]
+    pub fn designator(self) -> Option<RunwayDesignator> {
+        match self {
+            Self::Specific { designator, .. } => designator,
+            _ => None,
+        }
+    }
+
+    /// Get the runway at the opposite end of this one, for example 06L for 24R.
+    ///
+    /// Returns [`None`] if this is not a specific runway.
+    #[must_use]
+    pub fn reciprocal(self) -> Option<Runway> {
+        match self {
+            Self::Specific { number, designator } => Some(Self::Specific {
+                number: (number + 17) % 36 + 1,
+                designator: designator.map(RunwayDesignator::reciprocal),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Get the approximate magnetic heading of this runway in degrees.
+    ///
+    /// Returns [`None`] if this is not a specific runway.
+    #[must_use]
+    pub fn magnetic_heading(self) -> Option<u16> {
+        self.number().map(|number| u16::from(number) * 10)
+    }
+}
+
+impl fmt::Display for Runway {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Specific { number, designator } => {
+                write!(f, "{number:02}")?;
+                if let Some(designator) = designator {
+                    write!(f, "{designator}")?;
+                }
+                Ok(())
+            }
+            Self::AllRunways => write!(f, "88"),
+            Self::RepetitionOfLastMessage => write!(f, "99"),
+        }
+    }
+}
+
+impl Parsable for Runway {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        group((
+            text::digits(10)
+                .at_least(1)
+                .at_most(2)
+                .to_slice()
+                .try_map(|d: &str, span| {
+                    d.parse::<u8>()
+                        .ok()
+                        .filter(|v| *v <= 36 || *v == 88 || *v == 99)
+                        .ok_or(ErrorVariant::InvalidRvrRunwayNumber.into_err(span))
+                }),
+            RunwayDesignator::parser().or_not(),
+        ))
+        .map(|(number, designator)| match number {
+            88 => Runway::AllRunways,
+            99 => Runway::RepetitionOfLastMessage,
+            number => Runway::Specific { number, designator },
+        })
+    }
+}
+
+/// Which of a set of parallel runways is being referred to
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RunwayDesignator {
+    /// Left (L)
+    Left,
+    /// Centre (C)
+    Centre,
+    /// Right (R)
+    Right,
+}
+
+impl RunwayDesignator {
+    /// Get the designator of the same runway seen from the opposite end
+    #[must_use]
+    pub fn reciprocal(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Centre => Self::Centre,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+impl fmt::Display for RunwayDesignator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left => write!(f, "L"),
+            Self::Centre => write!(f, "C"),
+            Self::Right => write!(f, "R"),
+        }
+    }
+}
+
+impl Parsable for RunwayDesignator {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        choice((
+            just("L").map(|_| RunwayDesignator::Left),
+            just("C").map(|_| RunwayDesignator::Centre),
+            just("R").map(|_| RunwayDesignator::Right),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runway() {
+        assert_eq!(
+            Runway::parse("24L").unwrap(),
+            Runway::Specific {
+                number: 24,
+                designator: Some(RunwayDesignator::Left),
+            }
+        );
+        assert_eq!(
+            Runway::parse("6").unwrap(),
+            Runway::Specific {
+                number: 6,
+                designator: None,
+            }
+        );
+        assert_eq!(Runway::parse("88").unwrap(), Runway::AllRunways);
+        assert_eq!(
+            Runway::parse("99").unwrap(),
+            Runway::RepetitionOfLastMessage
+        );
+        assert!(Runway::parse("37").is_err());
+    }
+
+    #[test]
+    fn test_reciprocal() {
+        let runway = Runway::parse("24L").unwrap();
+        assert_eq!(runway.reciprocal(), Some(Runway::parse("06R").unwrap()));
+        assert_eq!(
+            Runway::parse("18C").unwrap().reciprocal(),
+            Some(Runway::parse("36C").unwrap())
+        );
+        assert_eq!(runway.magnetic_heading(), Some(240));
+        assert_eq!(Runway::AllRunways.reciprocal(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Runway::parse("6R").unwrap().to_string(), "06R");
+        assert_eq!(Runway::AllRunways.to_string(), "88");
+    }
+}
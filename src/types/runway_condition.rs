@@ -1,45 +1,115 @@
 use chumsky::prelude::*;
 
-use crate::{parsers::runway_number, traits::Parsable, Data};
+use crate::{
+    parsers::{end_of_group, runway_number},
+    traits::Parsable,
+    Data, ErrorVariant, Runway, RunwayDesignator,
+};
 
 /// Describes contamination on a runway
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RunwayCondition {
-    /// Affected runway number
-    pub runway_number: String,
+    /// Affected runway number. This is [`Runway::AllRunways`] for `R88/` and
+    /// `R/SNOCLO`, and [`Runway::RepetitionOfLastMessage`] for `R99/`.
+    pub runway_number: Runway,
     /// Contamination detail
     pub contamination: RunwayContamination,
-    /// Percentage of braking action on the runway
-    pub braking_action: Data<u8>,
+    /// Braking action on the runway. This is unknown if the runway is closed
+    /// by snow or reported in the Global Reporting Format.
+    pub braking_action: Data<BrakingAction>,
+}
+
+impl RunwayCondition {
+    /// Does this condition apply to the given runway? A condition reported
+    /// for all runways applies to every runway.
+    #[must_use]
+    pub fn applies_to(&self, runway: Runway) -> bool {
+        self.runway_number == runway || self.runway_number == Runway::AllRunways
+    }
+
+    /// Is this a repetition of the last report, as no new information is
+    /// available?
+    #[must_use]
+    pub fn is_repetition(&self) -> bool {
+        self.runway_number == Runway::RepetitionOfLastMessage
+    }
 }
 
 impl Parsable for RunwayCondition {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
-        group((
-            runway_number(),
-            just("/"),
-            RunwayContamination::parser(),
-            Data::parser_inline(
-                2,
-                text::digits(10)
-                    .exactly(2)
-                    .to_slice()
-                    .map(|d: &str| d.parse().unwrap()),
-            ),
+        let snow_closed = just("SNOCLO").map(|_| RunwayContamination::ClosedBySnow);
+        let legacy_state = || {
+            group((
+                RunwayContamination::parser(),
+                Data::parser_inline(2, BrakingAction::parser()),
+            ))
+        };
+
+        choice((
+            // The aerodrome is closed by snow
+            just("R/")
+                .ignore_then(snow_closed)
+                .map(|contamination| RunwayCondition {
+                    runway_number: Runway::AllRunways,
+                    contamination,
+                    braking_action: Data::Unknown,
+                }),
+            group((
+                runway_number(),
+                just("/"),
+                choice((
+                    snow_closed.map(|contamination| (contamination, Data::Unknown)),
+                    legacy_state(),
+                    RunwayThirdCondition::assessment_parser()
+                        .map(|thirds| (RunwayContamination::Assessed { thirds }, Data::Unknown)),
+                )),
+            ))
+            .map(|(runway_number, _, (contamination, braking_action))| {
+                RunwayCondition {
+                    runway_number,
+                    contamination,
+                    braking_action,
+                }
+            }),
+            // The eight figure form without the `R` prefix, such as `88290195`
+            group((legacy_runway_number(), legacy_state()))
+                .then_ignore(end_of_group())
+                .map(
+                    |(runway_number, (contamination, braking_action))| RunwayCondition {
+                        runway_number,
+                        contamination,
+                        braking_action,
+                    },
+                ),
         ))
-        .map(
-            |(runway_number, _, contamination, braking_action)| RunwayCondition {
-                runway_number,
-                contamination,
-                braking_action,
-            },
-        )
     }
 }
 
+/// Parse the two digit runway number of an eight figure runway state group.
+/// The right hand of a pair of parallel runways has 50 added to its number.
+fn legacy_runway_number<'src>(
+) -> impl Parser<'src, &'src str, Runway, extra::Err<crate::MetarError<'src>>> {
+    text::digits(10)
+        .exactly(2)
+        .to_slice()
+        .try_map(|d: &str, span| match d.parse::<u8>().unwrap() {
+            number @ 1..=36 => Ok(Runway::Specific {
+                number,
+                designator: None,
+            }),
+            number @ 51..=86 => Ok(Runway::Specific {
+                number: number - 50,
+                designator: Some(RunwayDesignator::Right),
+            }),
+            88 => Ok(Runway::AllRunways),
+            99 => Ok(Runway::RepetitionOfLastMessage),
+            _ => Err(ErrorVariant::InvalidRunwayStateRunwayNumber.into_err(span)),
+        })
+}
+
 /// Describes contamination on a runway
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RunwayContamination {
     /// Contamination is cleared
@@ -50,9 +120,17 @@ pub enum RunwayContamination {
         deposits: Data<RunwayDeposits>,
         /// Runway contamination
         contamination: Data<u8>,
-        /// Runway deposit depth, usually in millimetres, but figures
-        /// above 90 may have different interpretations
-        deposit_depth: Data<u8>,
+        /// Runway deposit depth
+        deposit_depth: Data<DepositDepth>,
+    },
+    /// The runway is closed due to snow (SNOCLO)
+    ClosedBySnow,
+    /// The runway condition has been assessed for each third of the runway,
+    /// in the ICAO Global Reporting Format
+    Assessed {
+        /// The condition of each third of the runway, in the direction of the
+        /// runway number
+        thirds: [RunwayThirdCondition; 3],
     },
 }
 
@@ -69,13 +147,7 @@ impl Parsable for RunwayContamination {
                         .to_slice()
                         .map(|d: &str| d.parse().unwrap()),
                 ),
-                Data::parser_inline(
-                    2,
-                    text::digits(10)
-                        .exactly(2)
-                        .to_slice()
-                        .map(|d: &str| d.parse().unwrap()),
-                ),
+                Data::parser_inline(2, DepositDepth::parser()),
             ))
             .map(|(deposits, contamination, deposit_depth)| {
                 RunwayContamination::Present {
@@ -89,7 +161,7 @@ impl Parsable for RunwayContamination {
 }
 
 /// Describes deposits on a runway
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[allow(missing_docs, reason = "clear what each means")]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RunwayDeposits {
@@ -121,3 +193,278 @@ impl Parsable for RunwayDeposits {
         ))
     }
 }
+
+/// The depth of a runway deposit
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DepositDepth {
+    /// A depth in millimetres. Codes 00 to 90 are the depth itself, and codes
+    /// 92 to 97 are 10cm to 35cm in steps of 5cm.
+    Millimetres(u16),
+    /// A depth of 40cm or more (98)
+    FortyCentimetresOrMore,
+    /// The runway is not operational due to deposits or their clearance,
+    /// and the depth is not reported (99)
+    RunwayNotOperational,
+}
+
+impl Parsable for DepositDepth {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        text::digits(10)
+            .exactly(2)
+            .to_slice()
+            .try_map(|d: &str, span| match d.parse::<u16>().unwrap() {
+                depth @ 0..=90 => Ok(DepositDepth::Millimetres(depth)),
+                code @ 92..=97 => Ok(DepositDepth::Millimetres((code - 90) * 50)),
+                98 => Ok(DepositDepth::FortyCentimetresOrMore),
+                99 => Ok(DepositDepth::RunwayNotOperational),
+                _ => Err(ErrorVariant::InvalidRunwayDepositDepth.into_err(span)),
+            })
+    }
+}
+
+/// The braking action on a runway
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BrakingAction {
+    /// A measured friction coefficient in hundredths, so 28 is 0.28 (00 to 90)
+    FrictionCoefficient(u8),
+    /// Poor (91)
+    Poor,
+    /// Medium to poor (92)
+    MediumToPoor,
+    /// Medium (93)
+    Medium,
+    /// Medium to good (94)
+    MediumToGood,
+    /// Good (95)
+    Good,
+    /// The braking action is unreliable or cannot be measured (99)
+    Unreliable,
+}
+
+impl Parsable for BrakingAction {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        text::digits(10)
+            .exactly(2)
+            .to_slice()
+            .try_map(|d: &str, span| match d.parse::<u8>().unwrap() {
+                coefficient @ 0..=90 => Ok(BrakingAction::FrictionCoefficient(coefficient)),
+                91 => Ok(BrakingAction::Poor),
+                92 => Ok(BrakingAction::MediumToPoor),
+                93 => Ok(BrakingAction::Medium),
+                94 => Ok(BrakingAction::MediumToGood),
+                95 => Ok(BrakingAction::Good),
+                99 => Ok(BrakingAction::Unreliable),
+                _ => Err(ErrorVariant::InvalidRunwayBrakingAction.into_err(span)),
+            })
+    }
+}
+
+/// The assessed condition of one third of a runway, in the ICAO Global
+/// Reporting Format
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunwayThirdCondition {
+    /// The runway condition code (RWYCC), from 0 for the least braking to 6
+    /// for a dry runway
+    pub condition_code: u8,
+    /// The percentage of this third covered by contaminants, unknown if not
+    /// reported
+    pub coverage: Data<u8>,
+    /// The depth of loose contaminants in millimetres, unknown if not reported
+    pub depth: Data<u16>,
+}
+
+impl RunwayThirdCondition {
+    /// Parse the groups of a runway condition assessment, such as
+    /// `5/5/2 100/100/50 NR/NR/03`. The RWYCC group is required, and the
+    /// coverage and depth groups may be omitted, although the depth group is
+    /// only given after the coverage group.
+    fn assessment_parser<'src>(
+    ) -> impl Parser<'src, &'src str, [Self; 3], extra::Err<crate::MetarError<'src>>> {
+        let condition_code = text::digits(10)
+            .exactly(1)
+            .to_slice()
+            .try_map(|d: &str, span| {
+                d.parse::<u8>()
+                    .ok()
+                    .filter(|code| *code <= 6)
+                    .ok_or(ErrorVariant::InvalidRunwayConditionCode.into_err(span))
+            });
+        let coverage = text::inline_whitespace()
+            .at_least(1)
+            .ignore_then(per_third(not_reported(
+                |coverage: &u8| *coverage <= 100,
+                ErrorVariant::InvalidRunwayCoverage,
+            )));
+        let depth = text::inline_whitespace()
+            .at_least(1)
+            .ignore_then(per_third(not_reported(
+                |_: &u16| true,
+                ErrorVariant::InvalidRunwayDepth,
+            )))
+            .or_not();
+
+        group((per_third(condition_code), coverage.then(depth).or_not())).map(
+            |(codes, coverage_and_depth)| {
+                let (coverage, depth) = coverage_and_depth.unwrap_or(([Data::Unknown; 3], None));
+                let depth = depth.unwrap_or([Data::Unknown; 3]);
+                std::array::from_fn(|idx| RunwayThirdCondition {
+                    condition_code: codes[idx],
+                    coverage: coverage[idx],
+                    depth: depth[idx],
+                })
+            },
+        )
+    }
+}
+
+/// Parse a value that may be reported as `NR`, for not reported, failing with
+/// `error` if the value does not fit or is not valid
+fn not_reported<'src, T: std::str::FromStr>(
+    valid: impl Fn(&T) -> bool,
+    error: ErrorVariant,
+) -> impl Parser<'src, &'src str, Data<T>, extra::Err<crate::MetarError<'src>>> {
+    choice((
+        just("NR").map(|_| Data::Unknown),
+        text::digits(10)
+            .at_least(1)
+            .at_most(3)
+            .to_slice()
+            .try_map(move |d: &str, span| {
+                d.parse()
+                    .ok()
+                    .filter(&valid)
+                    .map(Data::Known)
+                    .ok_or(error.clone().into_err(span))
+            }),
+    ))
+}
+
+/// Parse a group with a value for each third of a runway, separated by `/`
+fn per_third<'src, T>(
+    parser: impl Parser<'src, &'src str, T, extra::Err<crate::MetarError<'src>>>,
+) -> impl Parser<'src, &'src str, [T; 3], extra::Err<crate::MetarError<'src>>> {
+    parser
+        .separated_by(just("/"))
+        .collect_exactly::<[_; 3]>()
+        .then_ignore(end_of_group())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_condition() {
+        let condition = RunwayCondition::parse("R24/459295").unwrap();
+        assert_eq!(
+            condition.contamination,
+            RunwayContamination::Present {
+                deposits: Data::Known(RunwayDeposits::DrySnow),
+                contamination: Data::Known(5),
+                deposit_depth: Data::Known(DepositDepth::Millimetres(100)),
+            }
+        );
+        assert_eq!(condition.braking_action, Data::Known(BrakingAction::Good));
+
+        let condition = RunwayCondition::parse("R06/710228").unwrap();
+        assert_eq!(
+            condition.braking_action,
+            Data::Known(BrakingAction::FrictionCoefficient(28))
+        );
+        assert!(RunwayCondition::parse("R06/710297").is_err());
+        assert!(RunwayCondition::parse("R06/719128").is_err());
+    }
+
+    #[test]
+    fn test_eight_figure_condition() {
+        let condition = RunwayCondition::parse("88290195").unwrap();
+        assert_eq!(condition.runway_number, Runway::AllRunways);
+        assert_eq!(
+            condition.contamination,
+            RunwayContamination::Present {
+                deposits: Data::Known(RunwayDeposits::WetOrWaterPatches),
+                contamination: Data::Known(9),
+                deposit_depth: Data::Known(DepositDepth::Millimetres(1)),
+            }
+        );
+        assert_eq!(condition.braking_action, Data::Known(BrakingAction::Good));
+
+        assert_eq!(
+            RunwayCondition::parse("74CLRD93").unwrap().runway_number,
+            Runway::parse("24R").unwrap()
+        );
+        assert_eq!(
+            RunwayCondition::parse("24//////").unwrap().runway_number,
+            Runway::parse("24").unwrap()
+        );
+        assert!(RunwayCondition::parse("99421594").unwrap().is_repetition());
+        assert!(RunwayCondition::parse("40290195").is_err());
+        assert!(RunwayCondition::parse("882901951").is_err());
+    }
+
+    #[test]
+    fn test_special_runways() {
+        let condition = RunwayCondition::parse("R88/CLRD//").unwrap();
+        assert!(condition.applies_to(Runway::parse("24L").unwrap()));
+        assert_eq!(condition.braking_action, Data::Unknown);
+
+        let condition = RunwayCondition::parse("R99/CLRD95").unwrap();
+        assert!(condition.is_repetition());
+
+        let condition = RunwayCondition::parse("R/SNOCLO").unwrap();
+        assert_eq!(condition.contamination, RunwayContamination::ClosedBySnow);
+        assert!(condition.applies_to(Runway::parse("06").unwrap()));
+
+        let condition = RunwayCondition::parse("R24/SNOCLO").unwrap();
+        assert!(!condition.applies_to(Runway::parse("06").unwrap()));
+    }
+
+    #[test]
+    fn test_global_reporting_format() {
+        let condition = RunwayCondition::parse("R09L/5/5/2 100/100/50 NR/NR/03").unwrap();
+        assert_eq!(
+            condition.contamination,
+            RunwayContamination::Assessed {
+                thirds: [
+                    RunwayThirdCondition {
+                        condition_code: 5,
+                        coverage: Data::Known(100),
+                        depth: Data::Unknown,
+                    },
+                    RunwayThirdCondition {
+                        condition_code: 5,
+                        coverage: Data::Known(100),
+                        depth: Data::Unknown,
+                    },
+                    RunwayThirdCondition {
+                        condition_code: 2,
+                        coverage: Data::Known(50),
+                        depth: Data::Known(3),
+                    },
+                ],
+            }
+        );
+
+        let condition = RunwayCondition::parse("R27/6/6/6").unwrap();
+        let RunwayContamination::Assessed { thirds } = condition.contamination else {
+            panic!("expected an assessment");
+        };
+        assert!(thirds
+            .iter()
+            .all(|third| third.condition_code == 6 && third.coverage == Data::Unknown));
+        assert!(RunwayCondition::parse("R27/7/6/6").is_err());
+    }
+
+    #[test]
+    fn test_invalid_coverage() {
+        assert!(RunwayCondition::parse("R01L/5/5/2 999/100/50").is_err());
+        assert!(RunwayCondition::parse("R01L/5/5/2 101/100/50").is_err());
+        assert!(crate::Metar::parse(
+            "EFHK 121150Z 24005KT 9999 SCT030 M02/M05 Q1012 R01L/5/5/2 999/100/50"
+        )
+        .is_err());
+    }
+}
@@ -1,13 +1,13 @@
 use chumsky::prelude::*;
 
-use crate::{parsers::runway_number, traits::Parsable, Data, ErrorVariant};
+use crate::{parsers::runway_number, traits::Parsable, Data, ErrorVariant, Runway};
 
 /// The visibility measured for a specific runway.
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RunwayVisualRange {
     /// The runway this measurement applies to
-    pub runway: String,
+    pub runway: Runway,
     /// The visibility for this runway
     pub value: Data<RvrValue>,
     /// The visibility unit
@@ -16,6 +16,25 @@ pub struct RunwayVisualRange {
     pub trend: Data<RvrTrend>,
 }
 
+impl RunwayVisualRange {
+    /// Get this RVR with its values converted into the given unit
+    #[must_use]
+    pub fn in_unit(&self, unit: RvrUnit) -> Self {
+        Self {
+            value: self.value.map(|value| value.convert(self.unit, unit)),
+            unit,
+            ..*self
+        }
+    }
+
+    /// Get the representative RVR in the given unit, for comparing against
+    /// minima. This is the single value, or the lower of a varying range.
+    #[must_use]
+    pub fn representative(&self, unit: RvrUnit) -> Data<RvrValueInner> {
+        self.in_unit(unit).value.map(RvrValue::representative)
+    }
+}
+
 impl Parsable for RunwayVisualRange {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         group((
@@ -36,7 +55,7 @@ impl Parsable for RunwayVisualRange {
 }
 
 /// The visibility value
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RvrValue {
     /// There is a single value specified
@@ -45,6 +64,33 @@ pub enum RvrValue {
     Between(RvrValueInner, RvrValueInner),
 }
 
+impl RvrValue {
+    /// Get the representative value, which is the single value, or the lower
+    /// of a varying range
+    #[must_use]
+    pub fn representative(self) -> RvrValueInner {
+        match self {
+            Self::Single(value) => value,
+            Self::Between(a, b) => {
+                if a.distance() <= b.distance() {
+                    a
+                } else {
+                    b
+                }
+            }
+        }
+    }
+
+    /// Convert this value from one unit into another
+    #[must_use]
+    pub fn convert(self, from: RvrUnit, to: RvrUnit) -> Self {
+        match self {
+            Self::Single(value) => Self::Single(value.convert(from, to)),
+            Self::Between(a, b) => Self::Between(a.convert(from, to), b.convert(from, to)),
+        }
+    }
+}
+
 impl Parsable for RvrValue {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         RvrValueInner::parser()
@@ -54,7 +100,7 @@ impl Parsable for RvrValue {
             .collect::<Vec<_>>()
             .map(|vals| {
                 if vals.len() == 1 {
-                    RvrValue::Single(vals.first().unwrap().clone())
+                    RvrValue::Single(*vals.first().unwrap())
                 } else {
                     let mut iter = vals.into_iter();
                     RvrValue::Between(iter.next().unwrap(), iter.next().unwrap())
@@ -64,7 +110,7 @@ impl Parsable for RvrValue {
 }
 
 /// The visibility value
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RvrValueInner {
     /// The value is exactly
@@ -75,15 +121,41 @@ pub enum RvrValueInner {
     LessThan(u32),
 }
 
+impl RvrValueInner {
+    /// Get the distance of this value, ignoring whether it is a bound
+    #[must_use]
+    pub fn distance(self) -> u32 {
+        match self {
+            Self::Exactly(d) | Self::GreaterThan(d) | Self::LessThan(d) => d,
+        }
+    }
+
+    /// Convert this value from one unit into another
+    #[must_use]
+    pub fn convert(self, from: RvrUnit, to: RvrUnit) -> Self {
+        if from == to {
+            return self;
+        }
+        let convert = |distance| to.from_metres(from.to_metres(distance));
+        match self {
+            Self::Exactly(d) => Self::Exactly(convert(d)),
+            Self::GreaterThan(d) => Self::GreaterThan(convert(d)),
+            Self::LessThan(d) => Self::LessThan(convert(d)),
+        }
+    }
+}
+
 impl Parsable for RvrValueInner {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
-        let rvr_vis = text::digits(10)
-            .exactly(4)
-            .to_slice()
-            .try_map(|d: &str, span| {
-                d.parse::<u32>()
-                    .map_err(|_| ErrorVariant::InvalidRvrDistance.into_err(span))
-            });
+        let rvr_vis =
+            text::digits(10)
+                .at_least(3)
+                .at_most(4)
+                .to_slice()
+                .try_map(|d: &str, span| {
+                    d.parse::<u32>()
+                        .map_err(|_| ErrorVariant::InvalidRvrDistance.into_err(span))
+                });
 
         choice((
             just("P")
@@ -98,7 +170,7 @@ impl Parsable for RvrValueInner {
 }
 
 /// The unit of measurement
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RvrUnit {
     /// Metres
@@ -107,6 +179,32 @@ pub enum RvrUnit {
     Feet,
 }
 
+impl RvrUnit {
+    /// Convert a distance in this unit into metres, rounded to the nearest metre
+    #[must_use]
+    pub fn to_metres(self, distance: u32) -> u32 {
+        match self {
+            Self::Metres => distance,
+            Self::Feet => saturate((u64::from(distance) * 3048 + 5000) / 10_000),
+        }
+    }
+
+    /// Convert a distance in metres into this unit, rounded to the nearest
+    /// whole unit and saturating at [`u32::MAX`]
+    #[must_use]
+    pub fn from_metres(self, metres: u32) -> u32 {
+        match self {
+            Self::Metres => metres,
+            Self::Feet => saturate((u64::from(metres) * 10_000 + 1524) / 3048),
+        }
+    }
+}
+
+/// Convert a distance into a `u32`, saturating at [`u32::MAX`]
+fn saturate(distance: u64) -> u32 {
+    u32::try_from(distance).unwrap_or(u32::MAX)
+}
+
 impl Parsable for RvrUnit {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         choice((
@@ -117,7 +215,7 @@ impl Parsable for RvrUnit {
 }
 
 /// How is the RVR trending?
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RvrTrend {
     /// Trending upwards
@@ -142,13 +240,17 @@ impl Parsable for RvrTrend {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::RunwayDesignator;
 
     #[test]
     fn test_rvr() {
         assert_eq!(
             RunwayVisualRange::parse("R24L/P1500").unwrap(),
             RunwayVisualRange {
-                runway: "24L".to_string(),
+                runway: Runway::Specific {
+                    number: 24,
+                    designator: Some(RunwayDesignator::Left),
+                },
                 value: Data::Known(RvrValue::Single(RvrValueInner::GreaterThan(1500))),
                 unit: RvrUnit::Metres,
                 trend: Data::Known(RvrTrend::None),
@@ -157,7 +259,10 @@ mod tests {
         assert_eq!(
             RunwayVisualRange::parse("R25L/1800V3000FT").unwrap(),
             RunwayVisualRange {
-                runway: "25L".to_string(),
+                runway: Runway::Specific {
+                    number: 25,
+                    designator: Some(RunwayDesignator::Left),
+                },
                 value: Data::Known(RvrValue::Between(
                     RvrValueInner::Exactly(1800),
                     RvrValueInner::Exactly(3000)
@@ -167,4 +272,54 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_us_forms() {
+        let rvr = RunwayVisualRange::parse("R28/P6000FT").unwrap();
+        assert_eq!(
+            rvr.value,
+            Data::Known(RvrValue::Single(RvrValueInner::GreaterThan(6000)))
+        );
+        let rvr = RunwayVisualRange::parse("R10/M0600FT").unwrap();
+        assert_eq!(
+            rvr.value,
+            Data::Known(RvrValue::Single(RvrValueInner::LessThan(600)))
+        );
+        let rvr = RunwayVisualRange::parse("R06/550N").unwrap();
+        assert_eq!(
+            rvr.value,
+            Data::Known(RvrValue::Single(RvrValueInner::Exactly(550)))
+        );
+        assert!(RunwayVisualRange::parse("R06/55N").is_err());
+    }
+
+    #[test]
+    fn test_conversion() {
+        let rvr = RunwayVisualRange::parse("R25L/1800V3000FT").unwrap();
+        assert_eq!(
+            rvr.in_unit(RvrUnit::Metres).value,
+            Data::Known(RvrValue::Between(
+                RvrValueInner::Exactly(549),
+                RvrValueInner::Exactly(914)
+            ))
+        );
+        assert_eq!(
+            rvr.representative(RvrUnit::Feet),
+            Data::Known(RvrValueInner::Exactly(1800))
+        );
+        assert_eq!(
+            rvr.representative(RvrUnit::Metres),
+            Data::Known(RvrValueInner::Exactly(549))
+        );
+
+        let rvr = RunwayVisualRange::parse("R24/P1500").unwrap();
+        assert_eq!(
+            rvr.representative(RvrUnit::Feet),
+            Data::Known(RvrValueInner::GreaterThan(4921))
+        );
+        assert_eq!(RvrUnit::Feet.to_metres(6000), 1829);
+        assert_eq!(RvrUnit::Feet.from_metres(600), 1969);
+        assert_eq!(RvrUnit::Feet.to_metres(u32::MAX), 1_309_106_032);
+        assert_eq!(RvrUnit::Feet.from_metres(u32::MAX), u32::MAX);
+    }
 }
@@ -3,7 +3,7 @@ use chumsky::prelude::*;
 use crate::{parsers::temperature, traits::Parsable, Data};
 
 /// Describes the condition of the sea
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SeaCondition {
     /// Sea temperature
@@ -12,6 +12,48 @@ pub struct SeaCondition {
     pub condition: Data<SeaConditionInner>,
 }
 
+/// The number of feet in one metre
+const FEET_PER_METRE: f32 = 1. / 0.3048;
+
+impl SeaCondition {
+    /// The reported significant wave height in metres, if it was reported as
+    /// a height rather than a sea state
+    #[must_use]
+    pub fn wave_height_metres(&self) -> Option<f32> {
+        match self.condition {
+            Data::Known(SeaConditionInner::WaveHeight(Data::Known(decimetres))) =>
+            {
+                #[allow(clippy::cast_precision_loss, reason = "wave heights are small")]
+                Some(decimetres as f32 / 10.)
+            }
+            _ => None,
+        }
+    }
+
+    /// The reported significant wave height in feet, if it was reported as a
+    /// height rather than a sea state
+    #[must_use]
+    pub fn wave_height_feet(&self) -> Option<f32> {
+        self.wave_height_metres()
+            .map(|metres| metres * FEET_PER_METRE)
+    }
+
+    /// The range of wave heights in metres implied by this sea condition,
+    /// from either the sea state or the reported height. See
+    /// [`SeaState::wave_height_range_metres`].
+    #[must_use]
+    pub fn wave_height_range_metres(&self) -> Option<(f32, Option<f32>)> {
+        match self.condition {
+            Data::Known(SeaConditionInner::State(Data::Known(state))) => {
+                Some(state.wave_height_range_metres())
+            }
+            _ => self
+                .wave_height_metres()
+                .map(|height| (height, Some(height))),
+        }
+    }
+}
+
 impl Parsable for SeaCondition {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         group((
@@ -28,7 +70,7 @@ impl Parsable for SeaCondition {
 }
 
 /// Sea condition
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SeaConditionInner {
     /// Predefined sea state
@@ -57,8 +99,9 @@ impl Parsable for SeaConditionInner {
     }
 }
 
-/// The state of the sea
-#[derive(PartialEq, Eq, Clone, Debug)]
+/// The state of the sea, from WMO code table 3700, ordered from calmest to
+/// roughest
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
 #[allow(missing_docs)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SeaState {
@@ -74,6 +117,51 @@ pub enum SeaState {
     Phenomenal,
 }
 
+impl SeaState {
+    /// The range of wave heights in metres for this sea state, as the lower
+    /// bound and the upper bound, which is [`None`] for phenomenal seas
+    #[must_use]
+    pub fn wave_height_range_metres(self) -> (f32, Option<f32>) {
+        match self {
+            Self::CalmGlassy => (0., Some(0.)),
+            Self::CalmRippled => (0., Some(0.1)),
+            Self::Smooth => (0.1, Some(0.5)),
+            Self::Slight => (0.5, Some(1.25)),
+            Self::Moderate => (1.25, Some(2.5)),
+            Self::Rough => (2.5, Some(4.)),
+            Self::VeryRough => (4., Some(6.)),
+            Self::High => (6., Some(9.)),
+            Self::VeryHigh => (9., Some(14.)),
+            Self::Phenomenal => (14., None),
+        }
+    }
+
+    /// The range of wave heights in feet for this sea state. See
+    /// [`SeaState::wave_height_range_metres`].
+    #[must_use]
+    pub fn wave_height_range_feet(self) -> (f32, Option<f32>) {
+        let (min, max) = self.wave_height_range_metres();
+        (min * FEET_PER_METRE, max.map(|max| max * FEET_PER_METRE))
+    }
+
+    /// The description of this sea state on the Douglas sea scale
+    #[must_use]
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::CalmGlassy => "calm (glassy)",
+            Self::CalmRippled => "calm (rippled)",
+            Self::Smooth => "smooth (wavelets)",
+            Self::Slight => "slight",
+            Self::Moderate => "moderate",
+            Self::Rough => "rough",
+            Self::VeryRough => "very rough",
+            Self::High => "high",
+            Self::VeryHigh => "very high",
+            Self::Phenomenal => "phenomenal",
+        }
+    }
+}
+
 impl Parsable for SeaState {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         choice((
@@ -112,4 +200,33 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_negative_temperature() {
+        assert_eq!(
+            SeaCondition::parse("WM01/S3").unwrap(),
+            SeaCondition {
+                temperature: Data::Known(-1),
+                condition: Data::Known(SeaConditionInner::State(Data::Known(SeaState::Slight))),
+            }
+        );
+    }
+
+    #[test]
+    fn test_wave_heights() {
+        let condition = SeaCondition::parse("W15/H25").unwrap();
+        assert!((condition.wave_height_metres().unwrap() - 2.5).abs() < f32::EPSILON);
+        assert!((condition.wave_height_feet().unwrap() - 8.202).abs() < 0.001);
+
+        let condition = SeaCondition::parse("W15/S5").unwrap();
+        assert_eq!(condition.wave_height_metres(), None);
+        assert_eq!(condition.wave_height_range_metres(), Some((2.5, Some(4.))));
+        assert_eq!(SeaState::Phenomenal.wave_height_range_metres().1, None);
+        assert_eq!(SeaState::Rough.description(), "rough");
+        assert!(SeaState::Rough > SeaState::Moderate);
+
+        let (min, max) = SeaState::Slight.wave_height_range_feet();
+        assert!((min - 1.640).abs() < 0.001);
+        assert!((max.unwrap() - 4.101).abs() < 0.001);
+    }
 }
@@ -0,0 +1,142 @@
+use chrono::TimeDelta;
+
+/// How current a METAR is, compared to the present time
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Staleness {
+    /// The report is timestamped in the future, beyond the allowed tolerance
+    Future,
+    /// The report is current
+    Current,
+    /// The report is old and has likely been superseded
+    Stale,
+    /// The report is too old to be used
+    Expired,
+}
+
+/// The thresholds used to classify the [`Staleness`] of a METAR.
+///
+/// The thresholds should not be negative, and `stale_after` should be no
+/// later than `expired_after`, otherwise reports are never classified as
+/// stale. Use [`StalenessThresholds::new`] to check this.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StalenessThresholds {
+    /// Reports older than this are stale. Defaults to 1 hour.
+    pub stale_after: TimeDelta,
+    /// Reports older than this are expired. Defaults to 2 hours.
+    pub expired_after: TimeDelta,
+    /// Reports timestamped in the future by no more than this are treated as
+    /// current, to allow for clock differences. Defaults to 5 minutes.
+    pub future_tolerance: TimeDelta,
+}
+
+impl Default for StalenessThresholds {
+    fn default() -> Self {
+        Self {
+            stale_after: TimeDelta::hours(1),
+            expired_after: TimeDelta::hours(2),
+            future_tolerance: TimeDelta::minutes(5),
+        }
+    }
+}
+
+impl StalenessThresholds {
+    /// Create a new set of thresholds.
+    ///
+    /// Returns [`None`] if any threshold is negative, or `stale_after` is
+    /// later than `expired_after`.
+    #[must_use]
+    pub fn new(
+        stale_after: TimeDelta,
+        expired_after: TimeDelta,
+        future_tolerance: TimeDelta,
+    ) -> Option<Self> {
+        let valid = stale_after >= TimeDelta::zero()
+            && stale_after <= expired_after
+            && future_tolerance >= TimeDelta::zero();
+        valid.then_some(Self {
+            stale_after,
+            expired_after,
+            future_tolerance,
+        })
+    }
+
+    /// Classify the staleness of a report of the given age
+    #[must_use]
+    pub fn classify(&self, age: TimeDelta) -> Staleness {
+        if age < -self.future_tolerance {
+            Staleness::Future
+        } else if age > self.expired_after {
+            Staleness::Expired
+        } else if age > self.stale_after {
+            Staleness::Stale
+        } else {
+            Staleness::Current
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Metar;
+
+    fn metar() -> Metar {
+        Metar::parse("EGHI 312350Z 19015KT 9999 SCT030 16/14 Q1006").unwrap()
+    }
+
+    #[test]
+    fn test_age() {
+        let now = "2024-04-01T00:20:00Z".parse().unwrap();
+        assert_eq!(metar().age(now), Some(TimeDelta::minutes(30)));
+    }
+
+    #[test]
+    fn test_staleness() {
+        let thresholds = StalenessThresholds::default();
+        let at = |now: &str| metar().staleness(now.parse().unwrap(), &thresholds);
+        assert_eq!(at("2024-03-31T23:55:00Z"), Some(Staleness::Current));
+        assert_eq!(at("2024-03-31T23:47:00Z"), Some(Staleness::Current));
+        assert_eq!(at("2024-04-01T01:00:00Z"), Some(Staleness::Stale));
+        assert_eq!(at("2024-04-01T03:00:00Z"), Some(Staleness::Expired));
+        assert_eq!(at("2024-03-31T23:00:00Z"), Some(Staleness::Future));
+        assert!(metar().is_from_future("2024-03-31T23:00:00Z".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_large_future_tolerance() {
+        let thresholds = StalenessThresholds {
+            future_tolerance: TimeDelta::hours(2),
+            ..StalenessThresholds::default()
+        };
+        let at = |now: &str| metar().staleness(now.parse().unwrap(), &thresholds);
+        assert_eq!(at("2024-03-31T22:00:00Z"), Some(Staleness::Current));
+        assert_eq!(at("2024-03-31T21:00:00Z"), Some(Staleness::Future));
+        assert_eq!(at("2024-03-31T21:30:00Z"), Some(Staleness::Future));
+    }
+
+    #[test]
+    fn test_new_thresholds() {
+        let hour = TimeDelta::hours(1);
+        assert_eq!(
+            StalenessThresholds::new(hour, hour * 2, TimeDelta::minutes(5)),
+            Some(StalenessThresholds::default())
+        );
+        assert!(StalenessThresholds::new(hour * 2, hour, TimeDelta::zero()).is_none());
+        assert!(StalenessThresholds::new(-hour, hour, TimeDelta::zero()).is_none());
+        assert!(StalenessThresholds::new(hour, hour * 2, -hour).is_none());
+    }
+
+    #[test]
+    fn test_old_report() {
+        let metar = Metar::parse("EGHI 121150Z 19015KT 9999 SCT030 16/14 Q1006").unwrap();
+        let now = "2024-06-01T11:50:00Z".parse().unwrap();
+        assert_eq!(metar.age(now), Some(TimeDelta::days(20)));
+        assert_eq!(
+            metar.staleness(now, &StalenessThresholds::default()),
+            Some(Staleness::Expired)
+        );
+        assert!(!metar.is_from_future(now));
+    }
+}
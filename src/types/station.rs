@@ -0,0 +1,147 @@
+use chrono::NaiveDate;
+use chrono_tz::Tz;
+
+use crate::{Icao, SunTimes};
+
+mod data;
+use data::STATIONS;
+
+/// The mean radius of the Earth, in kilometres
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Information about a reporting station, from the embedded station table.
+///
+/// The embedded table is generated from the [mwgg/Airports] dataset by
+/// `examples/generate_stations.rs`. It covers airports with an ICAO
+/// identifier, so reporting stations that are not at an airport are not in
+/// it.
+///
+/// [mwgg/Airports]: https://github.com/mwgg/Airports
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Station {
+    /// The ICAO identifier of the station
+    pub icao: Icao,
+    /// The name of the station
+    pub name: &'static str,
+    /// The latitude of the station in degrees, positive north
+    pub latitude: f64,
+    /// The longitude of the station in degrees, positive east
+    pub longitude: f64,
+    /// The elevation of the station in feet above mean sea level
+    pub elevation: i32,
+    /// The ISO 3166-1 alpha-2 code of the country or territory the station is
+    /// in, for example `GB`
+    pub country: &'static str,
+    /// The IANA time zone of the station, for converting times into local time
+    pub timezone: Tz,
+}
+
+impl Station {
+    /// Get every station in the embedded table, sorted by ICAO identifier
+    #[must_use]
+    pub fn all() -> &'static [Station] {
+        STATIONS
+    }
+
+    /// Look up a station by its ICAO identifier.
+    ///
+    /// Returns [`None`] if the station is not in the embedded table.
+    #[must_use]
+    pub fn lookup(icao: Icao) -> Option<&'static Station> {
+        STATIONS
+            .binary_search_by_key(&icao, |station| station.icao)
+            .ok()
+            .map(|idx| &STATIONS[idx])
+    }
+
+    /// Find the station in the embedded table closest to the given
+    /// coordinates, in degrees, that is no more than `max_distance` kilometres
+    /// away.
+    ///
+    /// Returns [`None`] if there is no station within `max_distance`.
+    #[must_use]
+    pub fn nearest(latitude: f64, longitude: f64, max_distance: f64) -> Option<&'static Station> {
+        STATIONS
+            .iter()
+            .map(|station| (station, station.distance_to(latitude, longitude)))
+            .filter(|(_, distance)| *distance <= max_distance)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(station, _)| station)
+    }
+
+    /// The great circle distance from this station to the given coordinates,
+    /// in kilometres
+    #[must_use]
+    pub fn distance_to(&self, latitude: f64, longitude: f64) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), latitude.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (longitude - self.longitude).to_radians();
+
+        let a = (d_lat / 2.).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.).sin().powi(2);
+        2. * EARTH_RADIUS_KM * a.sqrt().asin()
+    }
+
+    /// Calculate the sun times at this station on a date. See [`SunTimes::on`].
+    #[must_use]
+    pub fn sun_times(&self, date: NaiveDate) -> SunTimes {
+        SunTimes::on(date, self.latitude, self.longitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Daylight, Metar};
+
+    #[test]
+    fn test_table_sorted() {
+        assert!(STATIONS.windows(2).all(|w| w[0].icao < w[1].icao));
+    }
+
+    #[test]
+    fn test_lookup() {
+        let station = Station::lookup("EGHI".parse().unwrap()).unwrap();
+        assert_eq!(station.name, "Southampton Airport");
+        assert_eq!(station.elevation, 44);
+        assert_eq!(station.timezone, Tz::Europe__London);
+        assert!(Station::lookup("XXXX".parse().unwrap()).is_none());
+
+        let metar = Metar::parse("KJFK 121851Z 18012KT 10SM FEW250 24/13 A3003").unwrap();
+        assert_eq!(metar.station_info().unwrap().country, "US");
+    }
+
+    #[test]
+    fn test_nearest() {
+        // Central London
+        let station = Station::nearest(51.5074, -0.1278, 50.).unwrap();
+        assert_eq!(station.icao, "EGLL");
+
+        let distance = station.distance_to(51.5074, -0.1278);
+        assert!((23. ..25.).contains(&distance));
+
+        // Nowhere near an airport, in the middle of the South Pacific
+        assert!(Station::nearest(-48.8767, -123.3933, 100.).is_none());
+    }
+
+    #[test]
+    fn test_time_zones() {
+        let metar = Metar::parse("KJFK 121851Z 18012KT 10SM FEW250 24/13 A3003").unwrap();
+        let reference = "2024-07-12T19:00:00Z".parse().unwrap();
+        assert_eq!(
+            metar
+                .station_local_time(reference)
+                .unwrap()
+                .naive_local()
+                .to_string(),
+            "2024-07-12 14:51:00"
+        );
+    }
+
+    #[test]
+    fn test_station_daylight() {
+        let metar = Metar::parse("EGLL 211200Z 24005KT CAVOK 25/10 Q1020").unwrap();
+        let reference = "2024-06-21T12:00:00Z".parse().unwrap();
+        assert_eq!(metar.station_daylight(reference), Some(Daylight::Day));
+    }
+}
@@ -0,0 +1,82 @@
+// Generated by `examples/generate_stations.rs` from the mwgg/Airports dataset,
+// https://github.com/mwgg/Airports, which is available under the MIT licence.
+// Regenerate this file rather than editing it by hand.
+
+use chrono_tz::Tz;
+
+use super::Station;
+use crate::Icao;
+
+/// The embedded station table, sorted by ICAO identifier
+#[rustfmt::skip]
+pub(super) static STATIONS: &[Station] = &[
+    Station { icao: Icao(*b"BIKF"), name: "Keflavik International Airport", latitude: 63.985, longitude: -22.6056, elevation: 171, country: "IS", timezone: Tz::Atlantic__Reykjavik },
+    Station { icao: Icao(*b"CYVR"), name: "Vancouver International Airport", latitude: 49.1939, longitude: -123.1844, elevation: 14, country: "CA", timezone: Tz::America__Vancouver },
+    Station { icao: Icao(*b"CYWG"), name: "Winnipeg James Armstrong Richardson International Airport", latitude: 49.91, longitude: -97.2399, elevation: 783, country: "CA", timezone: Tz::America__Winnipeg },
+    Station { icao: Icao(*b"CYYZ"), name: "Toronto Pearson International Airport", latitude: 43.6772, longitude: -79.6306, elevation: 569, country: "CA", timezone: Tz::America__Toronto },
+    Station { icao: Icao(*b"DNMM"), name: "Murtala Muhammed International Airport", latitude: 6.5774, longitude: 3.3212, elevation: 135, country: "NG", timezone: Tz::Africa__Lagos },
+    Station { icao: Icao(*b"EBBR"), name: "Brussels Airport", latitude: 50.9014, longitude: 4.4844, elevation: 184, country: "BE", timezone: Tz::Europe__Brussels },
+    Station { icao: Icao(*b"EDDF"), name: "Frankfurt am Main Airport", latitude: 50.0333, longitude: 8.5706, elevation: 364, country: "DE", timezone: Tz::Europe__Berlin },
+    Station { icao: Icao(*b"EDDK"), name: "Cologne Bonn Airport", latitude: 50.8659, longitude: 7.1427, elevation: 302, country: "DE", timezone: Tz::Europe__Berlin },
+    Station { icao: Icao(*b"EDDM"), name: "Munich Airport", latitude: 48.3538, longitude: 11.7861, elevation: 1487, country: "DE", timezone: Tz::Europe__Berlin },
+    Station { icao: Icao(*b"EFHK"), name: "Helsinki-Vantaa Airport", latitude: 60.3172, longitude: 24.9633, elevation: 179, country: "FI", timezone: Tz::Europe__Helsinki },
+    Station { icao: Icao(*b"EGCC"), name: "Manchester Airport", latitude: 53.3537, longitude: -2.275, elevation: 257, country: "GB", timezone: Tz::Europe__London },
+    Station { icao: Icao(*b"EGGD"), name: "Bristol Airport", latitude: 51.3827, longitude: -2.7191, elevation: 622, country: "GB", timezone: Tz::Europe__London },
+    Station { icao: Icao(*b"EGHI"), name: "Southampton Airport", latitude: 50.9503, longitude: -1.3568, elevation: 44, country: "GB", timezone: Tz::Europe__London },
+    Station { icao: Icao(*b"EGKK"), name: "London Gatwick Airport", latitude: 51.1481, longitude: -0.1903, elevation: 202, country: "GB", timezone: Tz::Europe__London },
+    Station { icao: Icao(*b"EGLL"), name: "London Heathrow Airport", latitude: 51.4706, longitude: -0.4619, elevation: 83, country: "GB", timezone: Tz::Europe__London },
+    Station { icao: Icao(*b"EGPC"), name: "Wick John O'Groats Airport", latitude: 58.4589, longitude: -3.0931, elevation: 126, country: "GB", timezone: Tz::Europe__London },
+    Station { icao: Icao(*b"EGPH"), name: "Edinburgh Airport", latitude: 55.95, longitude: -3.3725, elevation: 135, country: "GB", timezone: Tz::Europe__London },
+    Station { icao: Icao(*b"EGSS"), name: "London Stansted Airport", latitude: 51.885, longitude: 0.235, elevation: 348, country: "GB", timezone: Tz::Europe__London },
+    Station { icao: Icao(*b"EGVN"), name: "RAF Brize Norton", latitude: 51.75, longitude: -1.5836, elevation: 288, country: "GB", timezone: Tz::Europe__London },
+    Station { icao: Icao(*b"EHAM"), name: "Amsterdam Airport Schiphol", latitude: 52.3086, longitude: 4.7639, elevation: -11, country: "NL", timezone: Tz::Europe__Amsterdam },
+    Station { icao: Icao(*b"EIDW"), name: "Dublin Airport", latitude: 53.4213, longitude: -6.2701, elevation: 242, country: "IE", timezone: Tz::Europe__Dublin },
+    Station { icao: Icao(*b"EKCH"), name: "Copenhagen Airport", latitude: 55.6179, longitude: 12.656, elevation: 17, country: "DK", timezone: Tz::Europe__Copenhagen },
+    Station { icao: Icao(*b"ENGM"), name: "Oslo Airport, Gardermoen", latitude: 60.1939, longitude: 11.1004, elevation: 681, country: "NO", timezone: Tz::Europe__Oslo },
+    Station { icao: Icao(*b"ESSA"), name: "Stockholm Arlanda Airport", latitude: 59.6519, longitude: 17.9186, elevation: 137, country: "SE", timezone: Tz::Europe__Stockholm },
+    Station { icao: Icao(*b"FAOR"), name: "O. R. Tambo International Airport", latitude: -26.1392, longitude: 28.246, elevation: 5558, country: "ZA", timezone: Tz::Africa__Johannesburg },
+    Station { icao: Icao(*b"HECA"), name: "Cairo International Airport", latitude: 30.1219, longitude: 31.4056, elevation: 382, country: "EG", timezone: Tz::Africa__Cairo },
+    Station { icao: Icao(*b"HKJK"), name: "Jomo Kenyatta International Airport", latitude: -1.3192, longitude: 36.9278, elevation: 5330, country: "KE", timezone: Tz::Africa__Nairobi },
+    Station { icao: Icao(*b"HRYR"), name: "Kigali International Airport", latitude: -1.9686, longitude: 30.1395, elevation: 4859, country: "RW", timezone: Tz::Africa__Kigali },
+    Station { icao: Icao(*b"KATL"), name: "Hartsfield-Jackson Atlanta International Airport", latitude: 33.6367, longitude: -84.4281, elevation: 1026, country: "US", timezone: Tz::America__New_York },
+    Station { icao: Icao(*b"KBOS"), name: "Boston Logan International Airport", latitude: 42.3656, longitude: -71.0096, elevation: 20, country: "US", timezone: Tz::America__New_York },
+    Station { icao: Icao(*b"KDEN"), name: "Denver International Airport", latitude: 39.8617, longitude: -104.6731, elevation: 5434, country: "US", timezone: Tz::America__Denver },
+    Station { icao: Icao(*b"KDFW"), name: "Dallas/Fort Worth International Airport", latitude: 32.8968, longitude: -97.038, elevation: 607, country: "US", timezone: Tz::America__Chicago },
+    Station { icao: Icao(*b"KJFK"), name: "John F. Kennedy International Airport", latitude: 40.6398, longitude: -73.7789, elevation: 13, country: "US", timezone: Tz::America__New_York },
+    Station { icao: Icao(*b"KLAX"), name: "Los Angeles International Airport", latitude: 33.9425, longitude: -118.4081, elevation: 125, country: "US", timezone: Tz::America__Los_Angeles },
+    Station { icao: Icao(*b"KMHT"), name: "Manchester-Boston Regional Airport", latitude: 42.9326, longitude: -71.4357, elevation: 266, country: "US", timezone: Tz::America__New_York },
+    Station { icao: Icao(*b"KMIA"), name: "Miami International Airport", latitude: 25.7932, longitude: -80.2906, elevation: 8, country: "US", timezone: Tz::America__New_York },
+    Station { icao: Icao(*b"KORD"), name: "Chicago O'Hare International Airport", latitude: 41.9786, longitude: -87.9048, elevation: 672, country: "US", timezone: Tz::America__Chicago },
+    Station { icao: Icao(*b"KPHF"), name: "Newport News/Williamsburg International Airport", latitude: 37.1319, longitude: -76.493, elevation: 42, country: "US", timezone: Tz::America__New_York },
+    Station { icao: Icao(*b"KSEA"), name: "Seattle-Tacoma International Airport", latitude: 47.449, longitude: -122.3093, elevation: 433, country: "US", timezone: Tz::America__Los_Angeles },
+    Station { icao: Icao(*b"KSFO"), name: "San Francisco International Airport", latitude: 37.619, longitude: -122.3748, elevation: 13, country: "US", timezone: Tz::America__Los_Angeles },
+    Station { icao: Icao(*b"LEMD"), name: "Adolfo Suárez Madrid-Barajas Airport", latitude: 40.4719, longitude: -3.5626, elevation: 1998, country: "ES", timezone: Tz::Europe__Madrid },
+    Station { icao: Icao(*b"LFPG"), name: "Paris Charles de Gaulle Airport", latitude: 49.0097, longitude: 2.5479, elevation: 392, country: "FR", timezone: Tz::Europe__Paris },
+    Station { icao: Icao(*b"LFSB"), name: "EuroAirport Basel Mulhouse Freiburg", latitude: 47.59, longitude: 7.5292, elevation: 885, country: "FR", timezone: Tz::Europe__Paris },
+    Station { icao: Icao(*b"LIRF"), name: "Rome Fiumicino Airport", latitude: 41.8003, longitude: 12.2389, elevation: 13, country: "IT", timezone: Tz::Europe__Rome },
+    Station { icao: Icao(*b"LLBG"), name: "Ben Gurion Airport", latitude: 32.0114, longitude: 34.8867, elevation: 135, country: "IL", timezone: Tz::Asia__Jerusalem },
+    Station { icao: Icao(*b"LOWW"), name: "Vienna International Airport", latitude: 48.1103, longitude: 16.5697, elevation: 600, country: "AT", timezone: Tz::Europe__Vienna },
+    Station { icao: Icao(*b"LSZH"), name: "Zurich Airport", latitude: 47.4647, longitude: 8.5492, elevation: 1416, country: "CH", timezone: Tz::Europe__Zurich },
+    Station { icao: Icao(*b"LTFJ"), name: "Istanbul Sabiha Gökçen International Airport", latitude: 40.8986, longitude: 29.3092, elevation: 312, country: "TR", timezone: Tz::Europe__Istanbul },
+    Station { icao: Icao(*b"MDSD"), name: "Las Américas International Airport", latitude: 18.4297, longitude: -69.6689, elevation: 59, country: "DO", timezone: Tz::America__Santo_Domingo },
+    Station { icao: Icao(*b"MMMX"), name: "Mexico City International Airport", latitude: 19.4363, longitude: -99.0721, elevation: 7316, country: "MX", timezone: Tz::America__Mexico_City },
+    Station { icao: Icao(*b"NZAA"), name: "Auckland Airport", latitude: -37.0081, longitude: 174.7917, elevation: 23, country: "NZ", timezone: Tz::Pacific__Auckland },
+    Station { icao: Icao(*b"OMDB"), name: "Dubai International Airport", latitude: 25.2528, longitude: 55.3644, elevation: 62, country: "AE", timezone: Tz::Asia__Dubai },
+    Station { icao: Icao(*b"PANC"), name: "Ted Stevens Anchorage International Airport", latitude: 61.1744, longitude: -149.9964, elevation: 152, country: "US", timezone: Tz::America__Anchorage },
+    Station { icao: Icao(*b"PHNL"), name: "Daniel K. Inouye International Airport", latitude: 21.3187, longitude: -157.9225, elevation: 13, country: "US", timezone: Tz::Pacific__Honolulu },
+    Station { icao: Icao(*b"RJAA"), name: "Narita International Airport", latitude: 35.7647, longitude: 140.3864, elevation: 141, country: "JP", timezone: Tz::Asia__Tokyo },
+    Station { icao: Icao(*b"RJTT"), name: "Tokyo Haneda Airport", latitude: 35.5523, longitude: 139.7798, elevation: 35, country: "JP", timezone: Tz::Asia__Tokyo },
+    Station { icao: Icao(*b"RKSI"), name: "Incheon International Airport", latitude: 37.4691, longitude: 126.4505, elevation: 23, country: "KR", timezone: Tz::Asia__Seoul },
+    Station { icao: Icao(*b"SAEZ"), name: "Ministro Pistarini International Airport", latitude: -34.8222, longitude: -58.5358, elevation: 67, country: "AR", timezone: Tz::America__Argentina__Buenos_Aires },
+    Station { icao: Icao(*b"SBGR"), name: "São Paulo/Guarulhos International Airport", latitude: -23.4356, longitude: -46.4731, elevation: 2459, country: "BR", timezone: Tz::America__Sao_Paulo },
+    Station { icao: Icao(*b"UUDD"), name: "Moscow Domodedovo Airport", latitude: 55.4088, longitude: 37.9063, elevation: 588, country: "RU", timezone: Tz::Europe__Moscow },
+    Station { icao: Icao(*b"UUEE"), name: "Sheremetyevo International Airport", latitude: 55.9726, longitude: 37.4146, elevation: 622, country: "RU", timezone: Tz::Europe__Moscow },
+    Station { icao: Icao(*b"VHHH"), name: "Hong Kong International Airport", latitude: 22.308, longitude: 113.9185, elevation: 28, country: "HK", timezone: Tz::Asia__Hong_Kong },
+    Station { icao: Icao(*b"VIDP"), name: "Indira Gandhi International Airport", latitude: 28.5665, longitude: 77.1031, elevation: 777, country: "IN", timezone: Tz::Asia__Kolkata },
+    Station { icao: Icao(*b"WSSS"), name: "Singapore Changi Airport", latitude: 1.3502, longitude: 103.9944, elevation: 22, country: "SG", timezone: Tz::Asia__Singapore },
+    Station { icao: Icao(*b"YMML"), name: "Melbourne Airport", latitude: -37.6733, longitude: 144.8433, elevation: 434, country: "AU", timezone: Tz::Australia__Melbourne },
+    Station { icao: Icao(*b"YSSY"), name: "Sydney Kingsford Smith Airport", latitude: -33.9461, longitude: 151.1772, elevation: 21, country: "AU", timezone: Tz::Australia__Sydney },
+    Station { icao: Icao(*b"ZBAA"), name: "Beijing Capital International Airport", latitude: 40.0801, longitude: 116.5846, elevation: 116, country: "CN", timezone: Tz::Asia__Shanghai },
+    Station { icao: Icao(*b"ZGSZ"), name: "Shenzhen Bao'an International Airport", latitude: 22.6393, longitude: 113.8107, elevation: 13, country: "CN", timezone: Tz::Asia__Shanghai },
+    Station { icao: Icao(*b"ZSPD"), name: "Shanghai Pudong International Airport", latitude: 31.1434, longitude: 121.8052, elevation: 13, country: "CN", timezone: Tz::Asia__Shanghai },
+];
@@ -1,8 +1,9 @@
 use crate::{error::ErrorVariant, traits::Parsable, MetarError};
 
+use chrono::{DateTime, Datelike, Months, NaiveDate, TimeDelta, Utc};
 use chumsky::prelude::*;
 
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A struct to store time as it is represented in a METAR
 pub struct Time {
@@ -14,6 +15,85 @@ pub struct Time {
     pub minute: u8,
 }
 
+impl Time {
+    /// Resolve this time into a full UTC date and time.
+    ///
+    /// The METAR only gives the day of the month, so the month and year are
+    /// taken from `reference`, which should be close to when the report was
+    /// made, for example the time it was received. The closest matching day
+    /// in the month before, the month of, or the month after the reference
+    /// is chosen, so a report for day 31 received on the 1st of the month
+    /// resolves to the last day of the previous month.
+    ///
+    /// Returns [`None`] if no nearby month contains the reported day.
+    #[must_use]
+    pub fn resolve(&self, reference: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.candidates(reference)
+            .min_by_key(|candidate| (*candidate - reference).abs())
+    }
+
+    /// Resolve this time into the latest full UTC date and time that is no
+    /// later than `latest`, such as the current time.
+    ///
+    /// Unlike [`Time::resolve`], a report from more than half a month before
+    /// `latest` still resolves into the past, rather than into the next month.
+    ///
+    /// Returns [`None`] if no nearby month contains the reported day.
+    #[must_use]
+    pub fn resolve_before(&self, latest: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.candidates(latest)
+            .filter(|candidate| *candidate <= latest)
+            .max()
+    }
+
+    /// The dates and times this time could refer to in the month before, the
+    /// month of, and the month after `reference`
+    fn candidates(&self, reference: DateTime<Utc>) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+        let month_start = NaiveDate::from_ymd_opt(reference.year(), reference.month(), 1);
+        month_start
+            .into_iter()
+            .flat_map(|month_start| {
+                [
+                    month_start.checked_sub_months(Months::new(1)),
+                    Some(month_start),
+                    month_start.checked_add_months(Months::new(1)),
+                ]
+            })
+            .flatten()
+            .filter_map(|month| {
+                month.with_day(u32::from(self.date))?.and_hms_opt(
+                    u32::from(self.hour),
+                    u32::from(self.minute),
+                    0,
+                )
+            })
+            .map(|naive| naive.and_utc())
+    }
+}
+
+/// Resolve a time of day to the date and time nearest to `reference`, which
+/// may be on the previous or following day. An hour of 24 is treated as
+/// midnight at the end of the day.
+pub(crate) fn resolve_time_of_day(
+    hour: u8,
+    minute: u8,
+    reference: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let same_day = reference
+        .date_naive()
+        .and_hms_opt(0, 0, 0)?
+        .and_utc()
+        .checked_add_signed(TimeDelta::minutes(i64::from(hour) * 60 + i64::from(minute)))?;
+    [
+        same_day.checked_sub_signed(TimeDelta::days(1)),
+        Some(same_day),
+        same_day.checked_add_signed(TimeDelta::days(1)),
+    ]
+    .into_iter()
+    .flatten()
+    .min_by_key(|candidate| (*candidate - reference).abs())
+}
+
 impl Parsable for Time {
     fn parser<'src>() -> impl chumsky::Parser<'src, &'src str, Self, extra::Err<MetarError<'src>>> {
         let two_digits = text::digits(10).exactly(2).to_slice();
@@ -77,4 +157,72 @@ mod tests {
     fn invalid_minute() {
         assert!(Time::parse("010160Z").is_err());
     }
+
+    #[test]
+    fn resolve_same_month() {
+        let reference = "2024-03-15T12:30:00Z".parse().unwrap();
+        let time = Time::parse("151220Z").unwrap();
+        assert_eq!(
+            time.resolve(reference),
+            Some("2024-03-15T12:20:00Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn resolve_previous_month() {
+        let reference = "2024-01-01T00:10:00Z".parse().unwrap();
+        let time = Time::parse("312350Z").unwrap();
+        assert_eq!(
+            time.resolve(reference),
+            Some("2023-12-31T23:50:00Z".parse().unwrap())
+        );
+
+        // April has no 31st, so this must be March
+        let reference = "2024-04-01T00:10:00Z".parse().unwrap();
+        assert_eq!(
+            time.resolve(reference),
+            Some("2024-03-31T23:50:00Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn resolve_next_month() {
+        let reference = "2024-02-29T23:55:00Z".parse().unwrap();
+        let time = Time::parse("010000Z").unwrap();
+        assert_eq!(
+            time.resolve(reference),
+            Some("2024-03-01T00:00:00Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn resolve_before() {
+        let reference = "2024-06-01T12:00:00Z".parse().unwrap();
+        let time = Time::parse("121150Z").unwrap();
+        assert_eq!(
+            time.resolve(reference),
+            Some("2024-06-12T11:50:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            time.resolve_before(reference),
+            Some("2024-05-12T11:50:00Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn resolve_time_of_day_across_midnight() {
+        let reference = "2024-03-15T23:50:00Z".parse().unwrap();
+        assert_eq!(
+            resolve_time_of_day(1, 30, reference),
+            Some("2024-03-16T01:30:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            resolve_time_of_day(24, 0, reference),
+            Some("2024-03-16T00:00:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            resolve_time_of_day(23, 0, reference),
+            Some("2024-03-15T23:00:00Z".parse().unwrap())
+        );
+    }
 }
@@ -1,13 +1,16 @@
 use chumsky::prelude::*;
 
+use chrono::{DateTime, Utc};
+
 use crate::{
     parsers::{any_whitespace, some_whitespace},
     traits::Parsable,
-    CloudLayer, VerticalVisibility, Visibility, Weather, Wind,
+    types::time::resolve_time_of_day,
+    Clouds, ColourState, ErrorVariant, VerticalVisibility, Visibility, Weather, Wind,
 };
 
 /// How is the weather expected to change in the near future?
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub enum Trend {
@@ -28,26 +31,52 @@ impl Parsable for Trend {
             just("TEMPO ")
                 .then(TrendNewCondition::parser())
                 .map(|(_, cond)| Trend::Temporarily(cond)),
+            group((
+                just("PROB"),
+                text::digits(10)
+                    .exactly(2)
+                    .to_slice()
+                    .try_map(|d: &str, span| match d {
+                        "30" => Ok(30),
+                        "40" => Ok(40),
+                        _ => Err(ErrorVariant::InvalidTrendProbability.into_err(span)),
+                    }),
+                just(" TEMPO "),
+                TrendNewCondition::parser(),
+            ))
+            .map(|(_, probability, _, cond)| {
+                Trend::Temporarily(TrendNewCondition {
+                    probability: Some(probability),
+                    ..cond
+                })
+            }),
         ))
     }
 }
 
 /// New conditions apply
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrendNewCondition {
+    /// The probability of these conditions in percent, for `PROB30 TEMPO`
+    /// and `PROB40 TEMPO`
+    pub probability: Option<u8>,
     /// The time from which conditions apply
     pub time: Vec<TrendTime>,
     /// New wind values, if specified
     pub wind: Option<Wind>,
-    /// New visibility values, if specified
+    /// New visibility values, if specified. This is [`Visibility::CAVOK`] if
+    /// CAVOK is expected, as in the body of a METAR, and
+    /// [`crate::EffectiveConditions`] expands it into the conditions it implies.
     pub visibility: Option<Visibility>,
-    /// New weather conditions, if specified
-    pub weather: Vec<Weather>,
-    /// New cloud layers, if specified
-    pub cloud: Vec<CloudLayer>,
+    /// New weather conditions, if specified. This is empty for NSW.
+    pub weather: Option<Vec<Weather>>,
+    /// New sky condition, if specified, such as cloud layers or NSC
+    pub clouds: Option<Clouds>,
     /// New vertical visibility, if specified
     pub vertical_visibility: Option<VerticalVisibility>,
+    /// New military colour state, if specified
+    pub colour_code: Option<ColourState>,
 }
 
 impl Parsable for TrendNewCondition {
@@ -60,48 +89,110 @@ impl Parsable for TrendNewCondition {
                 .or(empty().map(|()| vec![])),
             Wind::parser().map(Some).or(empty().map(|()| None)),
             Visibility::parser()
-                .map(Some)
                 .then_ignore(any_whitespace())
+                .map(Some)
                 .or(empty().map(|()| None)),
             choice((
-                just("NSW").map(|_| vec![]).then_ignore(any_whitespace()),
+                just("NSW")
+                    .map(|_| Some(vec![]))
+                    .then_ignore(any_whitespace()),
                 Weather::parser()
                     .separated_by(some_whitespace())
                     .allow_trailing()
-                    .collect::<Vec<_>>(),
+                    .collect::<Vec<_>>()
+                    .map(|weather| (!weather.is_empty()).then_some(weather)),
             )),
-            CloudLayer::parser()
-                .separated_by(some_whitespace())
-                .allow_trailing()
-                .collect::<Vec<_>>(),
+            Clouds::parser().map(|clouds| match clouds {
+                Clouds::CloudLayers(layers) if layers.is_empty() => None,
+                clouds => Some(clouds),
+            }),
             VerticalVisibility::parser()
                 .then_ignore(any_whitespace())
                 .map(Some)
                 .or(empty().map(|()| None)),
+            ColourState::parser()
+                .then_ignore(any_whitespace())
+                .map(Some)
+                .or(empty().map(|()| None)),
         ))
         .map(
-            |(time, wind, visibility, weather, cloud, vertical_visibility)| TrendNewCondition {
-                time,
-                wind,
-                visibility,
-                weather,
-                cloud,
-                vertical_visibility,
+            |(time, wind, visibility, weather, clouds, vertical_visibility, colour_code)| {
+                TrendNewCondition {
+                    probability: None,
+                    time,
+                    wind,
+                    visibility,
+                    weather,
+                    clouds,
+                    vertical_visibility,
+                    colour_code,
+                }
             },
         )
     }
 }
 
 /// The time at which conditions change
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TrendTime {
-    /// From a particular time, in 24 hour format, eg. 1345
-    From(u16),
-    /// Until a particular time, in 24 hour format, eg. 1345
-    Until(u16),
-    /// At a particular time, in 24 hour format, eg. 1345
-    At(u16),
+    /// From a particular time, eg. FM1345
+    From {
+        /// The hour, in 24 hour format
+        hour: u8,
+        /// The minute
+        minute: u8,
+    },
+    /// Until a particular time, eg. TL1345. The hour may be 24 to indicate
+    /// the end of the day.
+    Until {
+        /// The hour, in 24 hour format
+        hour: u8,
+        /// The minute
+        minute: u8,
+    },
+    /// At a particular time, eg. AT1345
+    At {
+        /// The hour, in 24 hour format
+        hour: u8,
+        /// The minute
+        minute: u8,
+    },
+}
+
+impl TrendTime {
+    /// The time as given in the METAR, in 24 hour format, eg. 1345
+    #[must_use]
+    pub fn time(self) -> u16 {
+        u16::from(self.hour()) * 100 + u16::from(self.minute())
+    }
+
+    /// The hour of this time. This may be 24 to indicate the end of the day.
+    #[must_use]
+    pub fn hour(self) -> u8 {
+        match self {
+            Self::From { hour, .. } | Self::Until { hour, .. } | Self::At { hour, .. } => hour,
+        }
+    }
+
+    /// The minute of this time
+    #[must_use]
+    pub fn minute(self) -> u8 {
+        match self {
+            Self::From { minute, .. } | Self::Until { minute, .. } | Self::At { minute, .. } => {
+                minute
+            }
+        }
+    }
+
+    /// Resolve this time into a full UTC date and time, given the date and
+    /// time of the observation this trend belongs to, which can be obtained
+    /// with [`crate::Time::resolve`]. The time nearest to the observation is
+    /// chosen, so trends crossing midnight resolve to the following day.
+    #[must_use]
+    pub fn resolve(self, observation: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        resolve_time_of_day(self.hour(), self.minute(), observation)
+    }
 }
 
 impl Parsable for TrendTime {
@@ -109,13 +200,123 @@ impl Parsable for TrendTime {
         let time = text::digits(10)
             .exactly(4)
             .to_slice()
-            .map(|d: &str| d.parse().unwrap());
+            .try_map(|d: &str, span| {
+                let (hour, minute): (u8, u8) = (d[..2].parse().unwrap(), d[2..].parse().unwrap());
+                if minute >= 60 || hour > 24 || (hour == 24 && minute != 0) {
+                    return Err(ErrorVariant::InvalidTrendTime.into_err(span));
+                }
+                Ok((hour, minute))
+            });
         choice((
-            just("FM").then(time).map(|(_, time)| TrendTime::From(time)),
+            just("FM")
+                .ignore_then(time)
+                .map(|(hour, minute)| TrendTime::From { hour, minute }),
             just("TL")
-                .then(time)
-                .map(|(_, time)| TrendTime::Until(time)),
-            just("AT").then(time).map(|(_, time)| TrendTime::At(time)),
+                .ignore_then(time)
+                .map(|(hour, minute)| TrendTime::Until { hour, minute }),
+            just("AT")
+                .ignore_then(time)
+                .map(|(hour, minute)| TrendTime::At { hour, minute }),
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_trend_time() {
+        let time = TrendTime::parse("FM1345").unwrap();
+        assert_eq!(
+            time,
+            TrendTime::From {
+                hour: 13,
+                minute: 45
+            }
+        );
+        assert_eq!((time.hour(), time.minute(), time.time()), (13, 45, 1345));
+        assert_eq!(
+            TrendTime::parse("TL2400").unwrap(),
+            TrendTime::Until {
+                hour: 24,
+                minute: 0
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_trend_time() {
+        assert!(TrendTime::parse("FM1360").is_err());
+        assert!(TrendTime::parse("TL2500").is_err());
+        assert!(TrendTime::parse("AT2430").is_err());
+    }
+
+    #[test]
+    fn resolve_trend_time() {
+        let observation = "2024-03-31T23:20:00Z".parse().unwrap();
+        assert_eq!(
+            TrendTime::Until {
+                hour: 1,
+                minute: 30
+            }
+            .resolve(observation),
+            Some("2024-04-01T01:30:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            TrendTime::From {
+                hour: 23,
+                minute: 30
+            }
+            .resolve(observation),
+            Some("2024-03-31T23:30:00Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn trend_sky_condition() {
+        let Trend::Becoming(cond) = Trend::parse("BECMG NSC").unwrap() else {
+            panic!("expected a becoming trend");
+        };
+        assert_eq!(cond.clouds, Some(Clouds::NoSignificantCloud));
+        assert_eq!(cond.weather, None);
+
+        let Trend::Becoming(cond) = Trend::parse("BECMG FM1200 NSW SCT020").unwrap() else {
+            panic!("expected a becoming trend");
+        };
+        assert_eq!(cond.weather, Some(vec![]));
+        assert_eq!(cond.clouds.unwrap().layers().len(), 1);
+    }
+
+    #[test]
+    fn trend_cavok() {
+        let Trend::Temporarily(cond) = Trend::parse("TEMPO CAVOK").unwrap() else {
+            panic!("expected a temporary trend");
+        };
+        assert_eq!(cond.visibility, Some(Visibility::CAVOK));
+        assert_eq!(cond.weather, None);
+        assert_eq!(cond.clouds, None);
+
+        let Trend::Temporarily(cond) = Trend::parse("TEMPO 4000 -SHRA").unwrap() else {
+            panic!("expected a temporary trend");
+        };
+        assert_eq!(cond.visibility, Some(Visibility::Metres(4000)));
+    }
+
+    #[test]
+    fn trend_probability() {
+        let Trend::Temporarily(cond) = Trend::parse("PROB30 TEMPO TL1400 2000 TSRA").unwrap()
+        else {
+            panic!("expected a temporary trend");
+        };
+        assert_eq!(cond.probability, Some(30));
+        assert_eq!(
+            cond.time,
+            vec![TrendTime::Until {
+                hour: 14,
+                minute: 0
+            }]
+        );
+        assert!(Trend::parse("PROB50 TEMPO 2000").is_err());
+    }
+}
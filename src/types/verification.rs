@@ -0,0 +1,270 @@
+use chrono::{DateTime, Utc};
+
+use crate::{Clouds, Data, EffectiveConditions, Metar, Weather, Wind, WindDirection};
+
+/// Visibility thresholds in metres at which a trend should forecast a change
+const VISIBILITY_THRESHOLDS: [f32; 7] = [150., 350., 600., 800., 1500., 3000., 5000.];
+/// Cloud base thresholds in feet at which a trend should forecast a change
+const CLOUD_BASE_THRESHOLDS: [u32; 5] = [100, 200, 500, 1000, 1500];
+/// The change in mean wind speed, in knots, that a trend should forecast
+const WIND_SPEED_CHANGE_KNOTS: f32 = 10.;
+/// The change in wind direction, in degrees, that a trend should forecast
+const WIND_DIRECTION_CHANGE: u32 = 60;
+
+/// How many later observations verified a trend forecast for one element
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementScore {
+    /// The number of observations that matched the forecast
+    pub verified: usize,
+    /// The number of observations where the element could be compared
+    pub compared: usize,
+}
+
+impl ElementScore {
+    /// The fraction of compared observations that matched the forecast,
+    /// between 0 and 1.
+    ///
+    /// Returns [`None`] if no observations could be compared.
+    #[must_use]
+    pub fn score(self) -> Option<f32> {
+        #[allow(clippy::cast_precision_loss, reason = "observation counts are small")]
+        (self.compared > 0).then(|| self.verified as f32 / self.compared as f32)
+    }
+
+    /// Record the result of a comparison, if one could be made
+    fn record(&mut self, verified: Option<bool>) {
+        if let Some(verified) = verified {
+            self.compared += 1;
+            if verified {
+                self.verified += 1;
+            }
+        }
+    }
+}
+
+/// The verification of the trend forecast of a METAR against later
+/// observations from the same station
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrendVerification {
+    /// The number of later observations within the trend period
+    pub observations: usize,
+    /// The wind score
+    pub wind: ElementScore,
+    /// The visibility score
+    pub visibility: ElementScore,
+    /// The weather score
+    pub weather: ElementScore,
+    /// The cloud score
+    pub clouds: ElementScore,
+}
+
+impl TrendVerification {
+    /// The mean of the scores of each element that could be compared.
+    ///
+    /// Returns [`None`] if no element could be compared.
+    #[must_use]
+    pub fn overall(&self) -> Option<f32> {
+        let scores = [self.wind, self.visibility, self.weather, self.clouds]
+            .into_iter()
+            .filter_map(ElementScore::score)
+            .collect::<Vec<_>>();
+        #[allow(clippy::cast_precision_loss, reason = "there are four elements")]
+        (!scores.is_empty()).then(|| scores.iter().sum::<f32>() / scores.len() as f32)
+    }
+
+    /// Verify the trend forecast of a METAR against later observations. See
+    /// [`Metar::verify_trend`].
+    pub(crate) fn verify(
+        report: &Metar,
+        later: &[Metar],
+        reference: DateTime<Utc>,
+    ) -> Option<Self> {
+        if report.trends.is_empty() {
+            return None;
+        }
+        let observation = report.observation_time(reference)?;
+
+        let mut verification = TrendVerification::default();
+        for metar in later.iter().filter(|metar| metar.station == report.station) {
+            let Some(projected) = metar
+                .observation_time(observation)
+                .filter(|time| *time > observation)
+                .and_then(|time| report.conditions_at(time))
+            else {
+                continue;
+            };
+            let observed = metar.effective_conditions();
+            let forecasts = [&projected.expected, &projected.worst_case];
+            let verify =
+                |matches: fn(&EffectiveConditions, &EffectiveConditions) -> Option<bool>| {
+                    forecasts
+                        .iter()
+                        .map(|forecast| matches(forecast, &observed))
+                        .reduce(|a, b| match (a, b) {
+                            (Some(a), Some(b)) => Some(a || b),
+                            (a, b) => a.or(b),
+                        })
+                        .flatten()
+                };
+
+            verification.observations += 1;
+            verification.wind.record(verify(wind_matches));
+            verification.visibility.record(verify(visibility_matches));
+            verification.weather.record(verify(weather_matches));
+            verification.clouds.record(verify(clouds_matches));
+        }
+        Some(verification)
+    }
+}
+
+/// The mean wind speed in knots and direction in degrees, if known
+fn wind_components(wind: Wind) -> Option<(f32, Option<u32>)> {
+    match wind {
+        Wind::Calm => Some((0., None)),
+        Wind::Present { dir, speed, .. } => {
+            let direction = match dir {
+                WindDirection::Heading(Data::Known(heading)) => Some(heading),
+                _ => None,
+            };
+            speed.speed_knots().map(|speed| (speed, direction))
+        }
+    }
+}
+
+/// Does the observed wind match the forecast?
+fn wind_matches(forecast: &EffectiveConditions, observed: &EffectiveConditions) -> Option<bool> {
+    let (forecast_speed, forecast_dir) = wind_components(forecast.wind)?;
+    let (observed_speed, observed_dir) = wind_components(observed.wind)?;
+    if (forecast_speed - observed_speed).abs() >= WIND_SPEED_CHANGE_KNOTS {
+        return Some(false);
+    }
+    let significant_speed = forecast_speed.min(observed_speed) >= WIND_SPEED_CHANGE_KNOTS;
+    Some(match (forecast_dir, observed_dir) {
+        (Some(a), Some(b)) if significant_speed => {
+            let difference = a.abs_diff(b) % 360;
+            difference.min(360 - difference) < WIND_DIRECTION_CHANGE
+        }
+        _ => true,
+    })
+}
+
+/// Does the observed visibility fall within the same threshold band as the
+/// forecast?
+fn visibility_matches(
+    forecast: &EffectiveConditions,
+    observed: &EffectiveConditions,
+) -> Option<bool> {
+    let band = |conditions: &EffectiveConditions| match conditions.visibility {
+        Data::Known(visibility) => Some(
+            VISIBILITY_THRESHOLDS
+                .iter()
+                .filter(|threshold| visibility.is_at_least(**threshold))
+                .count(),
+        ),
+        Data::Unknown => None,
+    };
+    Some(band(forecast)? == band(observed)?)
+}
+
+/// Does the observed weather have the same significant phenomena as the
+/// forecast?
+fn weather_matches(forecast: &EffectiveConditions, observed: &EffectiveConditions) -> Option<bool> {
+    let significant = |conditions: &EffectiveConditions| match &conditions.weather {
+        Data::Known(weather) => {
+            let weather = weather.iter().filter(|wx| !wx.is_in_vicinity());
+            Some((
+                weather.clone().any(Weather::is_precipitation),
+                weather.clone().any(Weather::is_thunderstorm),
+                weather.clone().any(Weather::is_obscuration),
+            ))
+        }
+        Data::Unknown => None,
+    };
+    Some(significant(forecast)? == significant(observed)?)
+}
+
+/// Does the observed cloud base fall within the same threshold band as the
+/// forecast? Reports without a sky condition cannot be compared.
+fn clouds_matches(forecast: &EffectiveConditions, observed: &EffectiveConditions) -> Option<bool> {
+    let band = |conditions: &EffectiveConditions| {
+        if conditions.clouds == Clouds::CloudLayers(vec![]) && conditions.vert_visibility.is_none()
+        {
+            return None;
+        }
+        Some(
+            conditions
+                .cloud_base_feet()
+                .map_or(CLOUD_BASE_THRESHOLDS.len(), |base| {
+                    CLOUD_BASE_THRESHOLDS
+                        .iter()
+                        .filter(|threshold| base >= **threshold)
+                        .count()
+                }),
+        )
+    };
+    Some(band(forecast)? == band(observed)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_all(metars: &[&str]) -> Vec<Metar> {
+        metars
+            .iter()
+            .map(|metar| Metar::parse(metar).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_verified_trend() {
+        let metar = Metar::parse(
+            "EGLL 121150Z 24012KT 4000 -RA BKN012 12/05 Q1020 BECMG FM1230 9999 NSW SCT025",
+        )
+        .unwrap();
+        let later = parse_all(&[
+            "EGLL 121220Z 24010KT 4500 -RA BKN012 12/05 Q1020",
+            "EGLL 121250Z 25012KT 9999 FEW015 SCT030 13/05 Q1020",
+            "EGLL 121320Z 25011KT 9999 SCT028 13/05 Q1020",
+            "EGKK 121320Z 25011KT 0800 FG 13/05 Q1020",
+            "EGLL 121420Z 25011KT 0800 FG 13/05 Q1020",
+        ]);
+        let reference = "2024-06-12T12:00:00Z".parse().unwrap();
+
+        let verification = metar.verify_trend(&later, reference).unwrap();
+        assert_eq!(verification.observations, 3);
+        assert_eq!(verification.wind.score(), Some(1.));
+        assert_eq!(verification.visibility.score(), Some(1.));
+        assert_eq!(verification.weather.score(), Some(1.));
+        assert_eq!(verification.clouds.score(), Some(1.));
+        assert_eq!(verification.overall(), Some(1.));
+    }
+
+    #[test]
+    fn test_failed_trend() {
+        let metar = Metar::parse("EGLL 121150Z 24012KT 9999 FEW030 12/05 Q1020 NOSIG").unwrap();
+        let later = parse_all(&[
+            "EGLL 121220Z 24012KT 9999 FEW030 12/05 Q1020",
+            "EGLL 121250Z 32025KT 2000 +TSRA BKN008CB 10/08 Q1018",
+        ]);
+        let reference = "2024-06-12T12:00:00Z".parse().unwrap();
+
+        let verification = metar.verify_trend(&later, reference).unwrap();
+        assert_eq!(verification.observations, 2);
+        assert_eq!(
+            verification.wind,
+            ElementScore {
+                verified: 1,
+                compared: 2
+            }
+        );
+        assert_eq!(verification.visibility.score(), Some(0.5));
+        assert_eq!(verification.weather.score(), Some(0.5));
+        assert_eq!(verification.clouds.score(), Some(0.5));
+
+        let metar = Metar::parse("EGLL 121150Z 24012KT 9999 FEW030 12/05 Q1020").unwrap();
+        assert_eq!(metar.verify_trend(&later, reference), None);
+    }
+}
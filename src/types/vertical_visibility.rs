@@ -2,7 +2,7 @@ use chumsky::prelude::*;
 
 use crate::traits::Parsable;
 
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Vertical visibility measurement
 pub enum VerticalVisibility {
@@ -1,8 +1,8 @@
 use chumsky::prelude::*;
 
-use crate::{parsers::some_whitespace, traits::Parsable, Data};
+use crate::{parsers::some_whitespace, traits::Parsable, Data, ErrorVariant, Fraction};
 
-#[derive(PartialEq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[allow(missing_docs, reason = "clear what they are!")]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A compass direction
@@ -32,7 +32,10 @@ impl Parsable for CompassDirection {
     }
 }
 
-#[derive(PartialEq, Copy, Clone, Debug)]
+/// The number of metres in one statute mile
+const METRES_PER_STATUTE_MILE: f32 = 1609.344;
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Horizontal visibility
 pub enum Visibility {
@@ -40,8 +43,87 @@ pub enum Visibility {
     CAVOK,
     /// Metres
     Metres(u16),
+    /// 10 kilometres or more, reported as `9999`
+    TenKilometresOrMore,
+    /// Kilometres, reported with the `KM` unit
+    Kilometres(u16),
     /// Statute miles, usually used in the US
-    StatuteMiles(f32),
+    StatuteMiles(Fraction),
+    /// Less than the given number of statute miles, for example `M1/4SM`
+    LessThanStatuteMiles(Fraction),
+    /// More than the given number of statute miles, for example `P6SM`
+    GreaterThanStatuteMiles(Fraction),
+}
+
+impl Visibility {
+    /// Convert this visibility into metres. [`Visibility::CAVOK`] and
+    /// [`Visibility::TenKilometresOrMore`] are treated as 10km, and bounded
+    /// visibilities as their bound.
+    #[must_use]
+    pub fn to_metres(self) -> f32 {
+        match self {
+            Self::CAVOK | Self::TenKilometresOrMore => 10_000.,
+            Self::Metres(m) => f32::from(m),
+            Self::Kilometres(km) => f32::from(km) * 1000.,
+            Self::StatuteMiles(sm)
+            | Self::LessThanStatuteMiles(sm)
+            | Self::GreaterThanStatuteMiles(sm) => sm.to_f32() * METRES_PER_STATUTE_MILE,
+        }
+    }
+
+    /// Is this visibility known to be at least the given number of metres?
+    ///
+    /// A visibility less than some bound is never known to meet a minimum.
+    #[must_use]
+    pub fn is_at_least(self, metres: f32) -> bool {
+        match self {
+            Self::LessThanStatuteMiles(_) => false,
+            _ => self.to_metres() >= metres,
+        }
+    }
+
+    /// Is this visibility known to be below the given number of metres?
+    ///
+    /// A visibility of more than some bound, or of 10km or more, is never
+    /// known to be below a value greater than that bound.
+    #[must_use]
+    pub fn is_below(self, metres: f32) -> bool {
+        match self {
+            Self::LessThanStatuteMiles(_) => self.to_metres() <= metres,
+            Self::CAVOK | Self::TenKilometresOrMore | Self::GreaterThanStatuteMiles(_) => false,
+            _ => self.to_metres() < metres,
+        }
+    }
+}
+
+/// Parse a distance in statute miles, as a whole number, a fraction or both,
+/// followed by `SM`
+fn statute_miles<'src>(
+) -> impl Parser<'src, &'src str, Fraction, extra::Err<crate::MetarError<'src>>> {
+    let number = |max_digits| {
+        text::digits(10)
+            .at_least(1)
+            .at_most(max_digits)
+            .to_slice()
+            .map(|digits: &str| digits.parse::<u16>().unwrap())
+    };
+    let fraction = group((number(1), just("/"), number(2)))
+        .map(|(numerator, _, denominator)| (numerator, denominator));
+
+    choice((
+        // Whole and fractional miles
+        group((number(2), some_whitespace(), fraction))
+            .map(|(whole_part, (), fraction)| (whole_part, fraction)),
+        // Fractional miles
+        fraction.map(|fraction| (0, fraction)),
+        // Whole miles
+        number(2).map(|whole_part| (whole_part, (0, 1))),
+    ))
+    .try_map(|(whole_part, (numerator, denominator)), span| {
+        Fraction::from_mixed(whole_part, numerator, denominator)
+            .ok_or(ErrorVariant::InvalidVisibilityFraction.into_err(span))
+    })
+    .then_ignore(just("SM"))
 }
 
 impl Parsable for Visibility {
@@ -50,56 +132,26 @@ impl Parsable for Visibility {
     {
         choice((
             just("CAVOK").map(|_| Visibility::CAVOK),
-            // To compensate for a technically incorrect placement:
-            just("SKC").map(|_| Visibility::CAVOK),
+            just("9999").map(|_| Visibility::TenKilometresOrMore),
             // Metres
             text::digits(10)
                 .exactly(4)
                 .to_slice()
                 .map(|digits: &str| Visibility::Metres(digits.parse().unwrap())),
-            // Whole miles
+            // Kilometres
             text::digits(10)
                 .at_least(1)
                 .at_most(2)
                 .to_slice()
-                .then_ignore(just("SM"))
-                .map(|digits: &str| Visibility::StatuteMiles(digits.parse().unwrap())),
-            // Fractional miles
-            group((
-                text::digits(10).exactly(1).to_slice(),
-                just("/"),
-                text::digits(10).at_least(1).at_most(2).to_slice(),
-                just("SM"),
-            ))
-            .map(|(numerator, _, denominator, _): (&str, &str, &str, &str)| {
-                let numerator: f32 = numerator.parse().unwrap();
-                let denominator: f32 = denominator.parse().unwrap();
-                Visibility::StatuteMiles(numerator / denominator)
-            }),
-            // Whole and fractional miles
-            group((
-                text::digits(10).at_least(1).at_most(2).to_slice(),
-                some_whitespace(),
-                text::digits(10).exactly(1).to_slice(),
-                just("/"),
-                text::digits(10).exactly(1).to_slice(),
-                just("SM"),
-            ))
-            .map(
-                |(whole_part, (), numerator, _, denominator, _): (
-                    &str,
-                    (),
-                    &str,
-                    &str,
-                    &str,
-                    &str,
-                )| {
-                    let whole_part: f32 = whole_part.parse().unwrap();
-                    let numerator: f32 = numerator.parse().unwrap();
-                    let denominator: f32 = denominator.parse().unwrap();
-                    Visibility::StatuteMiles(whole_part + numerator / denominator)
-                },
-            ),
+                .then_ignore(just("KM"))
+                .map(|digits: &str| Visibility::Kilometres(digits.parse().unwrap())),
+            just("M")
+                .ignore_then(statute_miles())
+                .map(Visibility::LessThanStatuteMiles),
+            just("P")
+                .ignore_then(statute_miles())
+                .map(Visibility::GreaterThanStatuteMiles),
+            statute_miles().map(Visibility::StatuteMiles),
         ))
     }
 }
@@ -124,18 +176,53 @@ mod tests {
     #[test]
     fn valid_visibility() {
         assert_eq!(Visibility::parse("CAVOK").unwrap(), Visibility::CAVOK);
+        assert!(Visibility::parse("SKC").is_err());
         assert_eq!(Visibility::parse("5000").unwrap(), Visibility::Metres(5000));
         assert_eq!(
             Visibility::parse("3SM").unwrap(),
-            Visibility::StatuteMiles(3.)
+            Visibility::StatuteMiles(Fraction::from(3))
         );
         assert_eq!(
             Visibility::parse("1/4SM").unwrap(),
-            Visibility::StatuteMiles(0.25)
+            Visibility::StatuteMiles(Fraction::new(1, 4).unwrap())
         );
+        assert!(Visibility::parse("1/0SM").is_err());
         assert_eq!(
             Visibility::parse("3 1/2SM").unwrap(),
-            Visibility::StatuteMiles(3.5)
+            Visibility::StatuteMiles(Fraction::new(7, 2).unwrap())
         );
     }
+
+    #[test]
+    fn bounded_visibility() {
+        assert_eq!(
+            Visibility::parse("9999").unwrap(),
+            Visibility::TenKilometresOrMore
+        );
+        assert_eq!(
+            Visibility::parse("M1/4SM").unwrap(),
+            Visibility::LessThanStatuteMiles(Fraction::new(1, 4).unwrap())
+        );
+        assert_eq!(
+            Visibility::parse("P6SM").unwrap(),
+            Visibility::GreaterThanStatuteMiles(Fraction::from(6))
+        );
+        assert_eq!(Visibility::parse("8KM").unwrap(), Visibility::Kilometres(8));
+    }
+
+    #[test]
+    fn compare_visibility() {
+        assert!(Visibility::TenKilometresOrMore.is_at_least(10_000.));
+        assert!(!Visibility::TenKilometresOrMore.is_below(15_000.));
+        assert!(!Visibility::Metres(9000).is_at_least(10_000.));
+
+        let less_than = Visibility::LessThanStatuteMiles(Fraction::new(1, 4).unwrap());
+        assert!(!less_than.is_at_least(400.));
+        assert!(less_than.is_below(402.336));
+        assert!(!less_than.is_below(300.));
+
+        let more_than = Visibility::GreaterThanStatuteMiles(Fraction::from(6));
+        assert!(more_than.is_at_least(9000.));
+        assert!(!more_than.is_below(10_000.));
+    }
 }
@@ -1,33 +1,193 @@
 use chumsky::prelude::*;
 
-use crate::traits::Parsable;
+use crate::{parsers::end_of_group, traits::Parsable, ErrorVariant};
 
 use super::WeatherCondition;
+use super::WeatherDescriptor;
 use super::WeatherIntensity;
 
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-/// A weather information block
+/// A weather group, as described by WMO code table 4678: an intensity or
+/// proximity, an optional descriptor and the weather phenomena.
+///
+/// Groups that do not follow the code table but are seen in real reports, such
+/// as `+TSSHRA` with two descriptors or `FZBR`, are still parsed. Use
+/// [`Weather::is_valid`] to check a group against the code table.
 pub struct Weather {
-    /// The intensity of this weather block
+    /// The intensity or proximity of this weather group
     pub intensity: WeatherIntensity,
-    /// The weather condition/s this block describes.
-    pub conditions: Vec<WeatherCondition>,
+    /// The descriptors qualifying the phenomena. The code table allows at
+    /// most one, but this is a list so that groups with two descriptors seen
+    /// in real reports, such as `+TSSHRA` and `MIBCFG`, can still be parsed.
+    pub descriptors: Vec<WeatherDescriptor>,
+    /// The weather phenomena this group describes. This may be empty for a
+    /// thunderstorm without precipitation (`TS`) or showers in the vicinity
+    /// (`VCSH`).
+    pub phenomena: Vec<WeatherCondition>,
+}
+
+impl Weather {
+    /// Does this weather group include the given phenomenon?
+    #[must_use]
+    pub fn has_condition(&self, condition: WeatherCondition) -> bool {
+        self.phenomena.contains(&condition)
+    }
+
+    /// Does this weather group have the given descriptor?
+    #[must_use]
+    pub fn has_descriptor(&self, descriptor: WeatherDescriptor) -> bool {
+        self.descriptors.contains(&descriptor)
+    }
+
+    /// Does this weather group describe any precipitation?
+    #[must_use]
+    pub fn is_precipitation(&self) -> bool {
+        self.phenomena.iter().any(|c| c.is_precipitation())
+    }
+
+    /// Does this weather group describe any obscuration?
+    #[must_use]
+    pub fn is_obscuration(&self) -> bool {
+        self.phenomena.iter().any(|c| c.is_obscuration())
+    }
+
+    /// Does this weather group describe any other phenomena, such as squalls or sandstorms?
+    #[must_use]
+    pub fn is_other(&self) -> bool {
+        self.phenomena.iter().any(|c| c.is_other())
+    }
+
+    /// Is this weather freezing (FZ)?
+    #[must_use]
+    pub fn is_freezing(&self) -> bool {
+        self.has_descriptor(WeatherDescriptor::Freezing)
+    }
+
+    /// Is this weather showery (SH)?
+    #[must_use]
+    pub fn is_showers(&self) -> bool {
+        self.has_descriptor(WeatherDescriptor::Showers)
+    }
+
+    /// Does this weather group describe a thunderstorm (TS)?
+    #[must_use]
+    pub fn is_thunderstorm(&self) -> bool {
+        self.has_descriptor(WeatherDescriptor::Thunderstorm)
+    }
+
+    /// Does this weather group describe a tornado or waterspout (+FC)?
+    #[must_use]
+    pub fn is_tornado(&self) -> bool {
+        self.intensity == WeatherIntensity::Heavy
+            && self.has_condition(WeatherCondition::FunnelCloud)
+    }
+
+    /// Is this weather in the vicinity, rather than at the station?
+    #[must_use]
+    pub fn is_in_vicinity(&self) -> bool {
+        self.intensity == WeatherIntensity::InVicinity
+    }
+
+    /// Is this a valid combination of intensity, descriptor and phenomena?
+    ///
+    /// Only one descriptor may be given, and only precipitation may be
+    /// combined in a single group. Light or heavy
+    /// intensity applies only to precipitation, except for heavy funnel
+    /// clouds, sandstorms and duststorms. Each descriptor may only be used
+    /// with the phenomena it can describe. Only fog, volcanic ash, dust or sand
+    /// whirls, duststorms, sandstorms, funnel clouds, thunderstorms, showers
+    /// and blowing dust, sand or snow may be reported in the vicinity.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        use WeatherCondition as C;
+        let only = |allowed: &[WeatherCondition]| {
+            !self.phenomena.is_empty() && self.phenomena.iter().all(|c| allowed.contains(c))
+        };
+
+        if self.descriptors.len() > 1
+            || (self.phenomena.len() > 1 && !self.phenomena.iter().all(|c| c.is_precipitation()))
+        {
+            return false;
+        }
+
+        let intensity_valid = match self.intensity {
+            WeatherIntensity::Light => self.is_precipitation(),
+            WeatherIntensity::Heavy => {
+                self.is_precipitation() || only(&[C::FunnelCloud, C::Sandstorm, C::Duststorm])
+            }
+            _ => true,
+        };
+
+        let descriptor_valid = match self.descriptors.first() {
+            None => !self.phenomena.is_empty(),
+            Some(
+                WeatherDescriptor::Shallow
+                | WeatherDescriptor::Partial
+                | WeatherDescriptor::Patches,
+            ) => only(&[C::Fog]),
+            Some(WeatherDescriptor::LowDrifting) => only(&[C::WidespreadDust, C::Sand, C::Snow]),
+            Some(WeatherDescriptor::Blowing) => {
+                only(&[C::WidespreadDust, C::Sand, C::Snow, C::Spray])
+            }
+            Some(WeatherDescriptor::Freezing) => {
+                only(&[C::Fog, C::Drizzle, C::Rain, C::UnknownPrecipitation])
+            }
+            Some(WeatherDescriptor::Showers) => {
+                (self.phenomena.is_empty() && self.is_in_vicinity())
+                    || only(&[
+                        C::Rain,
+                        C::Snow,
+                        C::IcePellets,
+                        C::Hail,
+                        C::SnowPelletsOrSmallHail,
+                        C::UnknownPrecipitation,
+                    ])
+            }
+            Some(WeatherDescriptor::Thunderstorm) => {
+                self.phenomena.iter().all(|c| c.is_precipitation())
+            }
+        };
+
+        let vicinity_valid = !self.is_in_vicinity()
+            || match self.descriptors.first() {
+                None => only(&[
+                    C::Fog,
+                    C::VolcanicAsh,
+                    C::Dust,
+                    C::Duststorm,
+                    C::Sandstorm,
+                    C::FunnelCloud,
+                ]),
+                Some(WeatherDescriptor::Thunderstorm | WeatherDescriptor::Showers) => {
+                    self.phenomena.is_empty()
+                }
+                Some(WeatherDescriptor::Blowing) => only(&[C::WidespreadDust, C::Sand, C::Snow]),
+                Some(_) => false,
+            };
+
+        intensity_valid && descriptor_valid && vicinity_valid
+    }
 }
 
 impl Parsable for Weather {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
-        WeatherIntensity::parser()
-            .then(
-                WeatherCondition::parser()
-                    .repeated()
-                    .at_least(1)
-                    .collect::<Vec<_>>(),
-            )
-            .map(|(intensity, conditions)| Weather {
+        group((
+            WeatherIntensity::parser(),
+            WeatherDescriptor::parser().repeated().collect::<Vec<_>>(),
+            WeatherCondition::parser().repeated().collect::<Vec<_>>(),
+        ))
+        .then_ignore(end_of_group())
+        .try_map(|(intensity, descriptors, phenomena), span| {
+            if descriptors.is_empty() && phenomena.is_empty() {
+                return Err(ErrorVariant::InvalidWeather.into_err(span));
+            }
+            Ok(Weather {
                 intensity,
-                conditions,
+                descriptors,
+                phenomena,
             })
+        })
     }
 }
 
@@ -41,15 +201,92 @@ mod tests {
             Weather::parse("+SHRA").unwrap(),
             Weather {
                 intensity: WeatherIntensity::Heavy,
-                conditions: vec![WeatherCondition::Showers, WeatherCondition::Rain,]
+                descriptors: vec![WeatherDescriptor::Showers],
+                phenomena: vec![WeatherCondition::Rain],
             }
         );
         assert_eq!(
             Weather::parse("VCTS").unwrap(),
             Weather {
                 intensity: WeatherIntensity::InVicinity,
-                conditions: vec![WeatherCondition::Thunderstorm,]
+                descriptors: vec![WeatherDescriptor::Thunderstorm],
+                phenomena: vec![],
+            }
+        );
+        assert_eq!(
+            Weather::parse("-TSRASNGS").unwrap(),
+            Weather {
+                intensity: WeatherIntensity::Light,
+                descriptors: vec![WeatherDescriptor::Thunderstorm],
+                phenomena: vec![
+                    WeatherCondition::Rain,
+                    WeatherCondition::Snow,
+                    WeatherCondition::SnowPelletsOrSmallHail,
+                ],
             }
         );
     }
+
+    #[test]
+    fn test_tornado() {
+        let weather = Weather::parse("+FC").unwrap();
+        assert!(weather.is_tornado());
+        assert!(!Weather::parse("FC").unwrap().is_tornado());
+    }
+
+    #[test]
+    fn test_invalid_weather() {
+        for unparsable in ["", "RAFZ", "BR HZ", "+"] {
+            assert!(
+                Weather::parse(unparsable).is_err(),
+                "{unparsable} should not parse"
+            );
+        }
+        for invalid in [
+            "BRHZ", "+BR", "-FC", "MIRA", "FZSN", "SHFG", "SH", "BL", "-FZRASN", "+TSSHRA", "FZBR",
+            "MIBCFG", "VCRA", "VCSN", "VCBR", "VCTSRA", "VCSHRA", "VCFZFG",
+        ] {
+            assert!(
+                !Weather::parse(invalid).unwrap().is_valid(),
+                "{invalid} should be invalid"
+            );
+        }
+        for valid in [
+            "VCSH", "TS", "BCFG", "FZFG", "+BLSN", "DRSA", "+SS", "RESHRA", "UP", "FZUP", "VCFG",
+            "VCTS", "VCPO", "VCFC", "VCBLSN", "VCVA",
+        ] {
+            assert!(
+                Weather::parse(valid).unwrap().is_valid(),
+                "{valid} should be valid"
+            );
+        }
+    }
+
+    #[test]
+    fn test_nonstandard_weather() {
+        let weather = Weather::parse("+TSSHRA").unwrap();
+        assert!(weather.is_thunderstorm());
+        assert!(weather.is_showers());
+        assert!(weather.has_condition(WeatherCondition::Rain));
+
+        let metar = crate::Metar::parse(
+            "EGLL 121150Z 24012KT 3000 -FZRASN FZBR MIBCFG OVC004 M01/M02 Q1002",
+        )
+        .unwrap();
+        assert_eq!(metar.weather.unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_weather_queries() {
+        let weather = Weather::parse("-FZRA").unwrap();
+        assert!(weather.is_precipitation());
+        assert!(weather.is_freezing());
+        assert!(!weather.is_obscuration());
+        assert!(weather.has_condition(WeatherCondition::Rain));
+
+        let weather = Weather::parse("VCFG").unwrap();
+        assert!(weather.is_obscuration());
+        assert!(weather.is_in_vicinity());
+        assert!(!weather.is_precipitation());
+    }
 }
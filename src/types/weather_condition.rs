@@ -2,26 +2,10 @@ use chumsky::prelude::*;
 
 use crate::traits::Parsable;
 
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-/// Descriptor of weather
+/// A weather phenomenon
 pub enum WeatherCondition {
-    /// Descriptor - Shallow (MI)
-    Shallow,
-    /// Descriptor - Partial (PR)
-    Partial,
-    /// Descriptor - Patches (BC)
-    Patches,
-    /// Descriptor - Low drifting (DR)
-    LowDrifting,
-    /// Descriptor - Blowing (BL)
-    Blowing,
-    /// Descriptor - Showers (SH)
-    Showers,
-    /// Descriptor - Thunderstorm (TS)
-    Thunderstorm,
-    /// Descriptor - Freezing (FZ)
-    Freezing,
     /// Precipitation - Rain (RA)
     Rain,
     /// Precipitation - Drizzle (DZ)
@@ -68,17 +52,68 @@ pub enum WeatherCondition {
     FunnelCloud,
 }
 
+/// The category of a [`WeatherCondition`]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WeatherCategory {
+    /// A form of precipitation
+    Precipitation,
+    /// Something reducing visibility
+    Obscuration,
+    /// Other phenomena
+    Other,
+}
+
+impl WeatherCondition {
+    /// Get the category of this condition
+    #[must_use]
+    pub fn category(self) -> WeatherCategory {
+        match self {
+            Self::Rain
+            | Self::Drizzle
+            | Self::Snow
+            | Self::SnowGrains
+            | Self::IceCrystals
+            | Self::IcePellets
+            | Self::Hail
+            | Self::SnowPelletsOrSmallHail
+            | Self::UnknownPrecipitation => WeatherCategory::Precipitation,
+            Self::Fog
+            | Self::VolcanicAsh
+            | Self::Mist
+            | Self::Haze
+            | Self::WidespreadDust
+            | Self::Smoke
+            | Self::Sand
+            | Self::Spray => WeatherCategory::Obscuration,
+            Self::Squall | Self::Dust | Self::Duststorm | Self::Sandstorm | Self::FunnelCloud => {
+                WeatherCategory::Other
+            }
+        }
+    }
+
+    /// Is this condition a form of precipitation?
+    #[must_use]
+    pub fn is_precipitation(self) -> bool {
+        self.category() == WeatherCategory::Precipitation
+    }
+
+    /// Is this condition an obscuration?
+    #[must_use]
+    pub fn is_obscuration(self) -> bool {
+        self.category() == WeatherCategory::Obscuration
+    }
+
+    /// Is this condition one of the other phenomena?
+    #[must_use]
+    pub fn is_other(self) -> bool {
+        self.category() == WeatherCategory::Other
+    }
+}
+
 impl Parsable for WeatherCondition {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         choice((
-            just("MI").map(|_| WeatherCondition::Shallow),
-            just("PR").map(|_| WeatherCondition::Partial),
-            just("BC").map(|_| WeatherCondition::Patches),
-            just("DR").map(|_| WeatherCondition::LowDrifting),
-            just("BL").map(|_| WeatherCondition::Blowing),
-            just("SH").map(|_| WeatherCondition::Showers),
-            just("TS").map(|_| WeatherCondition::Thunderstorm),
-            just("FZ").map(|_| WeatherCondition::Freezing),
             just("RA").map(|_| WeatherCondition::Rain),
             just("DZ").map(|_| WeatherCondition::Drizzle),
             just("SN").map(|_| WeatherCondition::Snow),
@@ -97,13 +132,26 @@ impl Parsable for WeatherCondition {
             just("SA").map(|_| WeatherCondition::Sand),
             just("PY").map(|_| WeatherCondition::Spray),
             just("SQ").map(|_| WeatherCondition::Squall),
-            // Too many for one choice, split into two!
-        ))
-        .or(choice((
             just("PO").map(|_| WeatherCondition::Dust),
             just("DS").map(|_| WeatherCondition::Duststorm),
             just("SS").map(|_| WeatherCondition::Sandstorm),
             just("FC").map(|_| WeatherCondition::FunnelCloud),
-        )))
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_categories() {
+        assert!(WeatherCondition::SnowGrains.is_precipitation());
+        assert!(WeatherCondition::Mist.is_obscuration());
+        assert!(WeatherCondition::FunnelCloud.is_other());
+        assert_eq!(
+            WeatherCondition::Spray.category(),
+            WeatherCategory::Obscuration
+        );
     }
 }
@@ -0,0 +1,40 @@
+use chumsky::prelude::*;
+
+use crate::traits::Parsable;
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A descriptor qualifying the phenomena in a weather group
+pub enum WeatherDescriptor {
+    /// Shallow (MI)
+    Shallow,
+    /// Partial (PR)
+    Partial,
+    /// Patches (BC)
+    Patches,
+    /// Low drifting (DR)
+    LowDrifting,
+    /// Blowing (BL)
+    Blowing,
+    /// Showers (SH)
+    Showers,
+    /// Thunderstorm (TS)
+    Thunderstorm,
+    /// Freezing (FZ)
+    Freezing,
+}
+
+impl Parsable for WeatherDescriptor {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        choice((
+            just("MI").map(|_| WeatherDescriptor::Shallow),
+            just("PR").map(|_| WeatherDescriptor::Partial),
+            just("BC").map(|_| WeatherDescriptor::Patches),
+            just("DR").map(|_| WeatherDescriptor::LowDrifting),
+            just("BL").map(|_| WeatherDescriptor::Blowing),
+            just("SH").map(|_| WeatherDescriptor::Showers),
+            just("TS").map(|_| WeatherDescriptor::Thunderstorm),
+            just("FZ").map(|_| WeatherDescriptor::Freezing),
+        ))
+    }
+}
@@ -2,7 +2,7 @@ use chumsky::prelude::*;
 
 use crate::traits::Parsable;
 
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Intensity of weather
 pub enum WeatherIntensity {
@@ -18,6 +18,33 @@ pub enum WeatherIntensity {
     Recent,
 }
 
+impl WeatherIntensity {
+    /// Rank this intensity from light to heavy. Weather in the vicinity or
+    /// recent weather has no intensity, so has no rank.
+    fn rank(self) -> Option<u8> {
+        match self {
+            Self::Light => Some(0),
+            Self::Moderate => Some(1),
+            Self::Heavy => Some(2),
+            Self::InVicinity | Self::Recent => None,
+        }
+    }
+
+    /// Find the strongest of a set of intensities, ignoring weather in the
+    /// vicinity and recent weather.
+    #[must_use]
+    pub fn strongest<I>(intensities: I) -> Option<WeatherIntensity>
+    where
+        I: IntoIterator<Item = WeatherIntensity>,
+    {
+        intensities
+            .into_iter()
+            .filter_map(|i| i.rank().map(|r| (r, i)))
+            .max_by_key(|(r, _)| *r)
+            .map(|(_, i)| i)
+    }
+}
+
 impl Parsable for WeatherIntensity {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         choice((
@@ -29,3 +56,24 @@ impl Parsable for WeatherIntensity {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strongest() {
+        assert_eq!(
+            WeatherIntensity::strongest([
+                WeatherIntensity::Light,
+                WeatherIntensity::InVicinity,
+                WeatherIntensity::Moderate,
+            ]),
+            Some(WeatherIntensity::Moderate)
+        );
+        assert_eq!(
+            WeatherIntensity::strongest([WeatherIntensity::InVicinity]),
+            None
+        );
+    }
+}
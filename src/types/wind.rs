@@ -2,16 +2,22 @@ use chumsky::prelude::*;
 
 use crate::parsers::some_whitespace;
 use crate::traits::Parsable;
+use crate::ErrorVariant;
 
 use super::Data;
 use super::WindDirection;
 use super::WindSpeed;
 
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+/// The narrowest sector, in degrees, that should be reported as varying
+const MIN_VARYING_SECTOR: u32 = 60;
+/// The lowest speed, in knots, at which a varying sector should be reported
+const MIN_VARYING_SPEED_KNOTS: f32 = 3.;
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Wind information.
 pub enum Wind {
-    /// Calm winds are at 0 kts
+    /// Calm winds, reported as `CALM` or with a speed of zero, such as `00000KT`
     Calm,
     /// Winds are present. More information is available in the struct.
     Present {
@@ -24,8 +30,63 @@ pub enum Wind {
     },
 }
 
+/// An inconsistency in a reported wind, which suggests it has been reported
+/// or decoded incorrectly
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindInconsistency {
+    /// The gust speed is lower than the mean wind speed
+    GustsBelowMean,
+    /// The variable sector is narrower than 60°
+    NarrowVaryingSector,
+    /// A variable sector is reported with a mean wind speed below 3 knots
+    VaryingInLightWind,
+}
+
+impl Wind {
+    /// Check this wind for inconsistencies. An empty list means that no
+    /// inconsistencies were found.
+    #[must_use]
+    pub fn inconsistencies(&self) -> Vec<WindInconsistency> {
+        let mut inconsistencies = vec![];
+        let Self::Present { speed, varying, .. } = *self else {
+            return inconsistencies;
+        };
+
+        if let (Some(mean), Some(gust)) = (speed.speed_knots(), speed.gust_knots()) {
+            if gust < mean {
+                inconsistencies.push(WindInconsistency::GustsBelowMean);
+            }
+        }
+
+        if let Some((from, to)) = varying {
+            if let (Data::Known(from), Data::Known(to)) = (from, to) {
+                if (to + 360 - from) % 360 < MIN_VARYING_SECTOR {
+                    inconsistencies.push(WindInconsistency::NarrowVaryingSector);
+                }
+            }
+            if speed
+                .speed_knots()
+                .is_some_and(|mean| mean < MIN_VARYING_SPEED_KNOTS)
+            {
+                inconsistencies.push(WindInconsistency::VaryingInLightWind);
+            }
+        }
+
+        inconsistencies
+    }
+}
+
 impl Parsable for Wind {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        // A heading in a varying group, which cannot be variable
+        let heading = || {
+            WindDirection::parser().try_map(|dir, span| match dir {
+                WindDirection::Heading(heading) => Ok(heading),
+                WindDirection::Variable => Err(ErrorVariant::InvalidWindHeading.into_err(span)),
+            })
+        };
+
         choice((
             just("CALM")
                 .map(|_| Wind::Calm)
@@ -34,17 +95,60 @@ impl Parsable for Wind {
                 WindDirection::parser(),
                 WindSpeed::parser().then_ignore(some_whitespace()),
                 choice((
-                    group((WindDirection::parser(), just("V"), WindDirection::parser()))
-                        .map(|(from, _, to)| Some((from.unwrap_heading(), to.unwrap_heading())))
+                    group((heading(), just("V"), heading()))
+                        .map(|(from, _, to)| Some((from, to)))
                         .then_ignore(some_whitespace()),
                     empty().map(|()| None),
                 )),
             ))
-            .map(|(dir, speed, varying)| Wind::Present {
-                dir,
-                speed,
-                varying,
+            .map(|(dir, speed, varying)| {
+                if speed.is_calm() {
+                    Wind::Calm
+                } else {
+                    Wind::Present {
+                        dir,
+                        speed,
+                        varying,
+                    }
+                }
             }),
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calm_wind() {
+        assert_eq!(Wind::parse("00000KT ").unwrap(), Wind::Calm);
+        assert_eq!(Wind::parse("00000MPS ").unwrap(), Wind::Calm);
+        assert_eq!(Wind::parse("CALM ").unwrap(), Wind::Calm);
+        assert_ne!(Wind::parse("00000G10KT ").unwrap(), Wind::Calm);
+    }
+
+    #[test]
+    fn variable_in_varying_group() {
+        assert!(Wind::parse("27010KT VRBV300 ").is_err());
+    }
+
+    #[test]
+    fn wind_inconsistencies() {
+        let inconsistencies = |wind: &str| Wind::parse(wind).unwrap().inconsistencies();
+        assert!(inconsistencies("31006KT 270V340 ").is_empty());
+        assert!(inconsistencies("03010KT 350V050 ").is_empty());
+        assert_eq!(
+            inconsistencies("27020G15KT "),
+            vec![WindInconsistency::GustsBelowMean]
+        );
+        assert_eq!(
+            inconsistencies("27010KT 250V290 "),
+            vec![WindInconsistency::NarrowVaryingSector]
+        );
+        assert_eq!(
+            inconsistencies("27002KT 240V300 "),
+            vec![WindInconsistency::VaryingInLightWind]
+        );
+    }
+}
@@ -12,15 +12,6 @@ pub enum WindDirection {
     Variable,
 }
 
-impl WindDirection {
-    pub(crate) fn unwrap_heading(self) -> Data<u32> {
-        match self {
-            Self::Heading(v) => v,
-            Self::Variable => panic!(),
-        }
-    }
-}
-
 impl Parsable for WindDirection {
     fn parser<'src>() -> impl chumsky::Parser<'src, &'src str, Self, extra::Err<MetarError<'src>>> {
         choice((
@@ -27,83 +27,117 @@ pub enum WindSpeed {
         /// The wind gusts speed
         gusting: Option<Data<u32>>,
     },
-    /// Wind speed is greater than 100 knots, 100 m/s or 200 kph
+    /// Wind speed is greater than the value reported, which is given with a
+    /// `P` prefix, for example `P99KT`
     Greater,
 }
 
+/// The number of knots in one metre per second
+const KNOTS_PER_METRE_PER_SECOND: f32 = 1.943_844;
+/// The number of kilometres per hour in one knot
+const KILOMETRES_PER_HOUR_PER_KNOT: f32 = 1.852;
+
+impl WindSpeed {
+    /// Get the mean wind speed in knots.
+    ///
+    /// Returns [`None`] if the speed is unknown or is [`WindSpeed::Greater`].
+    #[must_use]
+    pub fn speed_knots(&self) -> Option<f32> {
+        match *self {
+            Self::Knots { speed, .. }
+            | Self::MetresPerSecond { speed, .. }
+            | Self::KilometresPerHour { speed, .. } => match speed {
+                Data::Known(speed) => Some(self.to_knots(speed)),
+                Data::Unknown => None,
+            },
+            Self::Greater => None,
+        }
+    }
+
+    /// Get the wind gust speed in knots.
+    ///
+    /// Returns [`None`] if no gusts were reported or their speed is unknown.
+    #[must_use]
+    pub fn gust_knots(&self) -> Option<f32> {
+        match *self {
+            Self::Knots { gusting, .. }
+            | Self::MetresPerSecond { gusting, .. }
+            | Self::KilometresPerHour { gusting, .. } => match gusting {
+                Some(Data::Known(gust)) => Some(self.to_knots(gust)),
+                _ => None,
+            },
+            Self::Greater => None,
+        }
+    }
+
+    /// Is this a known speed of zero with no gusts?
+    pub(crate) fn is_calm(self) -> bool {
+        match self {
+            Self::Knots { speed, gusting }
+            | Self::MetresPerSecond { speed, gusting }
+            | Self::KilometresPerHour { speed, gusting } => {
+                speed == Data::Known(0) && gusting.is_none()
+            }
+            Self::Greater => false,
+        }
+    }
+
+    /// Convert a value in this wind speed's unit into knots
+    #[allow(clippy::cast_precision_loss, reason = "wind speeds are small")]
+    fn to_knots(self, value: u32) -> f32 {
+        let value = value as f32;
+        match self {
+            Self::Knots { .. } | Self::Greater => value,
+            Self::MetresPerSecond { .. } => value * KNOTS_PER_METRE_PER_SECOND,
+            Self::KilometresPerHour { .. } => value / KILOMETRES_PER_HOUR_PER_KNOT,
+        }
+    }
+}
+
+/// Parse a wind speed with optional gusts, each of between `min_digits` and
+/// three digits, followed by `unit`
+fn speed_and_gusts<'src>(
+    min_digits: usize,
+    unit: &'static str,
+) -> impl Parser<'src, &'src str, (Data<u32>, Option<Data<u32>>), extra::Err<MetarError<'src>>> {
+    let value = text::digits(10)
+        .at_least(min_digits)
+        .at_most(3)
+        .to_slice()
+        .map(|v: &str| v.parse().unwrap());
+
+    group((
+        choice((just("//").map(|_| Data::Unknown), value.map(Data::Known))),
+        choice((
+            just("G//").map(|_| Some(Data::Unknown)),
+            just("G")
+                .ignore_then(value)
+                .map(|gust| Some(Data::Known(gust))),
+            empty().map(|()| None),
+        )),
+        just(unit),
+    ))
+    .map(|(speed, gusting, _)| (speed, gusting))
+}
+
 impl Parsable for WindSpeed {
     fn parser<'src>() -> impl chumsky::Parser<'src, &'src str, Self, extra::Err<MetarError<'src>>> {
         choice((
             // Greater than speeds
-            just("P99KT").map(|_| WindSpeed::Greater),
-            just("P99MPS").map(|_| WindSpeed::Greater),
-            just("P199KPH").map(|_| WindSpeed::Greater),
-            // Knots
-            just("//KT").map(|_| WindSpeed::Knots {
-                speed: Data::Unknown,
-                gusting: None,
-            }),
             group((
-                text::digits(10).exactly(2).to_slice(),
-                choice((
-                    just("G//").map(|_| Some(Data::Unknown)),
-                    just("G")
-                        .then(text::digits(10).exactly(2).to_slice())
-                        .map(|(_, gust): (&str, &str)| Some(Data::Known(gust.parse().unwrap()))),
-                    empty().map(|()| None),
-                )),
-                just("KT"),
+                just("P"),
+                text::digits(10).at_least(2).at_most(3),
+                choice((just("KT"), just("MPS"), just("KPH"))),
             ))
-            .map(|(spd, gusting, _): (&str, Option<Data<u32>>, &str)| {
-                WindSpeed::Knots {
-                    speed: Data::Known(spd.parse().unwrap()),
-                    gusting,
-                }
-            }),
+            .map(|_| WindSpeed::Greater),
+            // Knots
+            speed_and_gusts(2, "KT").map(|(speed, gusting)| WindSpeed::Knots { speed, gusting }),
             // MPS
-            just("//MPS").map(|_| WindSpeed::MetresPerSecond {
-                speed: Data::Unknown,
-                gusting: None,
-            }),
-            group((
-                text::digits(10).exactly(2).to_slice(),
-                choice((
-                    just("G//").map(|_| Some(Data::Unknown)),
-                    just("G")
-                        .then(text::digits(10).exactly(2).to_slice())
-                        .map(|(_, gust): (&str, &str)| Some(Data::Known(gust.parse().unwrap()))),
-                    empty().map(|()| None),
-                )),
-                just("MPS"),
-            ))
-            .map(|(spd, gusting, _): (&str, Option<Data<u32>>, &str)| {
-                WindSpeed::MetresPerSecond {
-                    speed: Data::Known(spd.parse().unwrap()),
-                    gusting,
-                }
-            }),
+            speed_and_gusts(2, "MPS")
+                .map(|(speed, gusting)| WindSpeed::MetresPerSecond { speed, gusting }),
             // KPH
-            just("//KPH").map(|_| WindSpeed::KilometresPerHour {
-                speed: Data::Unknown,
-                gusting: None,
-            }),
-            group((
-                text::digits(10).exactly(3).to_slice(),
-                choice((
-                    just("G//").map(|_| Some(Data::Unknown)),
-                    just("G")
-                        .then(text::digits(10).exactly(3).to_slice())
-                        .map(|(_, gust): (&str, &str)| Some(Data::Known(gust.parse().unwrap()))),
-                    empty().map(|()| None),
-                )),
-                just("KPH"),
-            ))
-            .map(|(spd, gusting, _): (&str, Option<Data<u32>>, &str)| {
-                WindSpeed::KilometresPerHour {
-                    speed: Data::Known(spd.parse().unwrap()),
-                    gusting,
-                }
-            }),
+            speed_and_gusts(3, "KPH")
+                .map(|(speed, gusting)| WindSpeed::KilometresPerHour { speed, gusting }),
         ))
     }
 }
@@ -150,6 +184,33 @@ mod tests {
             }
         );
         assert_eq!(WindSpeed::parse("P99KT").unwrap(), WindSpeed::Greater);
+        assert_eq!(WindSpeed::parse("P199KT").unwrap(), WindSpeed::Greater);
+    }
+
+    #[test]
+    fn valid_three_digit() {
+        assert_eq!(
+            WindSpeed::parse("105G125KT").unwrap(),
+            WindSpeed::Knots {
+                speed: Data::Known(105),
+                gusting: Some(Data::Known(125))
+            }
+        );
+        assert_eq!(
+            WindSpeed::parse("95G110KT").unwrap(),
+            WindSpeed::Knots {
+                speed: Data::Known(95),
+                gusting: Some(Data::Known(110))
+            }
+        );
+        assert_eq!(
+            WindSpeed::parse("055G065MPS").unwrap(),
+            WindSpeed::MetresPerSecond {
+                speed: Data::Known(55),
+                gusting: Some(Data::Known(65))
+            }
+        );
+        assert!(WindSpeed::parse("1234KT").is_err());
     }
 
     #[test]
@@ -231,4 +292,13 @@ mod tests {
         );
         assert_eq!(WindSpeed::parse("P199KPH").unwrap(), WindSpeed::Greater);
     }
+
+    #[test]
+    fn speed_in_knots() {
+        let speed = WindSpeed::parse("10G20MPS").unwrap();
+        assert!((speed.speed_knots().unwrap() - 19.44).abs() < 0.01);
+        assert!((speed.gust_knots().unwrap() - 38.88).abs() < 0.01);
+        assert_eq!(WindSpeed::parse("//KT").unwrap().speed_knots(), None);
+        assert_eq!(WindSpeed::parse("P99KT").unwrap().gust_knots(), None);
+    }
 }
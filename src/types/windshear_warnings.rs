@@ -1,38 +1,52 @@
 use chumsky::prelude::*;
 
-use crate::{
-    parsers::{runway_number, some_whitespace},
-    traits::Parsable,
-};
+use crate::{parsers::some_whitespace, traits::Parsable, Runway};
 
-/// A windshear warnings
-#[derive(PartialEq, Clone, Debug)]
+/// Windshear warnings, which may be reported for all runways, specific
+/// runways, or both
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum WindshearWarnings {
-    /// All runways are affected by windshear
-    AllRunways,
-    /// Only specific runways are affected by windshear
-    SpecificRunways(Vec<WindshearGroup>),
+pub struct WindshearWarnings {
+    /// The windshear groups, in the order they were reported
+    pub groups: Vec<WindshearGroup>,
+}
+
+impl WindshearWarnings {
+    /// Is windshear reported for all runways?
+    #[must_use]
+    pub fn is_all_runways(&self) -> bool {
+        self.groups
+            .iter()
+            .any(|group| group.runway_number == Runway::AllRunways)
+    }
+
+    /// Get the windshear groups that affect the given runway, including those
+    /// reported for all runways
+    pub fn affecting(&self, runway: Runway) -> impl Iterator<Item = &WindshearGroup> {
+        self.groups.iter().filter(move |group| {
+            group.runway_number == runway || group.runway_number == Runway::AllRunways
+        })
+    }
 }
 
 impl Parsable for WindshearWarnings {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
-        choice((
-            just("WS ALL RWY").map(|_| WindshearWarnings::AllRunways),
-            WindshearGroup::parser()
-                .separated_by(some_whitespace())
-                .collect::<Vec<_>>()
-                .map(WindshearWarnings::SpecificRunways),
-        ))
+        WindshearGroup::parser()
+            .separated_by(some_whitespace())
+            .at_least(1)
+            .collect::<Vec<_>>()
+            .map(|groups| WindshearWarnings { groups })
     }
 }
 
 /// A runway affected by windshear
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WindshearGroup {
-    /// The runway number
-    pub runway_number: String,
+    /// The runway number, which is [`Runway::AllRunways`] for `WS ALL RWY`
+    pub runway_number: Runway,
+    /// Whether the windshear affects landing, take-off or both
+    pub phase: WindshearPhase,
 }
 
 impl Parsable for WindshearGroup {
@@ -40,8 +54,107 @@ impl Parsable for WindshearGroup {
         group((
             just("WS"),
             text::inline_whitespace().at_least(1),
-            runway_number(),
+            WindshearPhase::parser()
+                .then_ignore(text::inline_whitespace().at_least(1))
+                .or(empty().map(|()| WindshearPhase::Both)),
+            choice((
+                just("ALL RWY").map(|_| Runway::AllRunways),
+                just("RWY").ignore_then(Runway::parser()),
+                just("R").ignore_then(Runway::parser()),
+            )),
+        ))
+        .map(|(_, (), phase, runway_number)| WindshearGroup {
+            runway_number,
+            phase,
+        })
+    }
+}
+
+/// The phase of flight affected by windshear
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindshearPhase {
+    /// Landing (LDG)
+    Landing,
+    /// Take-off (TKOF)
+    TakeOff,
+    /// Both landing and take-off, as no phase was given
+    Both,
+}
+
+impl WindshearPhase {
+    /// Does the windshear affect landing?
+    #[must_use]
+    pub fn affects_landing(self) -> bool {
+        matches!(self, Self::Landing | Self::Both)
+    }
+
+    /// Does the windshear affect take-off?
+    #[must_use]
+    pub fn affects_take_off(self) -> bool {
+        matches!(self, Self::TakeOff | Self::Both)
+    }
+}
+
+impl Parsable for WindshearPhase {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        choice((
+            just("LDG").map(|_| WindshearPhase::Landing),
+            just("TKOF").map(|_| WindshearPhase::TakeOff),
         ))
-        .map(|(_, (), runway_number)| WindshearGroup { runway_number })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windshear_groups() {
+        let runway = Runway::parse("24L").unwrap();
+        let group = |runway_number, phase| WindshearGroup {
+            runway_number,
+            phase,
+        };
+
+        assert_eq!(
+            WindshearGroup::parse("WS R24L").unwrap(),
+            group(runway, WindshearPhase::Both)
+        );
+        assert_eq!(
+            WindshearGroup::parse("WS RWY24L").unwrap(),
+            group(runway, WindshearPhase::Both)
+        );
+        assert_eq!(
+            WindshearGroup::parse("WS LDG RWY24L").unwrap(),
+            group(runway, WindshearPhase::Landing)
+        );
+        assert_eq!(
+            WindshearGroup::parse("WS TKOF RWY24L").unwrap(),
+            group(runway, WindshearPhase::TakeOff)
+        );
+        assert_eq!(
+            WindshearGroup::parse("WS ALL RWY").unwrap(),
+            group(Runway::AllRunways, WindshearPhase::Both)
+        );
+    }
+
+    #[test]
+    fn test_windshear_warnings() {
+        let warnings = WindshearWarnings::parse("WS ALL RWY WS TKOF RWY06").unwrap();
+        assert!(warnings.is_all_runways());
+
+        let runway = Runway::parse("06").unwrap();
+        let phases = warnings
+            .affecting(runway)
+            .map(|group| group.phase)
+            .collect::<Vec<_>>();
+        assert_eq!(phases, vec![WindshearPhase::Both, WindshearPhase::TakeOff]);
+        assert!(phases.iter().all(|phase| phase.affects_take_off()));
+        assert!(!WindshearPhase::TakeOff.affects_landing());
+
+        let warnings = WindshearWarnings::parse("WS R16R WS R16L").unwrap();
+        assert!(!warnings.is_all_runways());
+        assert_eq!(warnings.affecting(runway).count(), 0);
     }
 }
@@ -1,5 +1,8 @@
 use cucumber::{then, when, World as _};
-use metar::{Data, Metar, OwnedMetarError, Pressure, Visibility, Wind, WindDirection, WindSpeed};
+use metar::{
+    Clouds, ColourCode, Data, Metar, OwnedMetarError, Pressure, Runway, RunwayContamination,
+    RunwayDesignator, Trend, TrendNewCondition, Visibility, Wind, WindDirection, WindSpeed,
+};
 
 #[derive(cucumber::World, Debug, Default)]
 struct World {
@@ -48,7 +51,7 @@ fn parse_fails_cleanly(w: &mut World) {
 #[then(expr = "the station is {string}")]
 fn check_station(w: &mut World, station: String) {
     let metar = w.metar();
-    assert_eq!(station, metar.station);
+    assert_eq!(metar.station, station.as_str());
 }
 
 #[then(expr = "the date is {int} {int} {int}")]
@@ -73,6 +76,12 @@ fn check_wind_dir(w: &mut World, target_dir: u32) {
     }
 }
 
+#[then(expr = "the wind direction is calm")]
+fn check_wind_dir_calm(w: &mut World) {
+    let metar = w.metar();
+    assert_eq!(metar.wind, Wind::Calm);
+}
+
 #[then(expr = "the wind direction is variable")]
 fn check_wind_dir_var(w: &mut World) {
     let metar = w.metar();
@@ -189,6 +198,12 @@ fn check_wind_spd_knots(w: &mut World, spd: u32) {
     }
 }
 
+#[then(expr = "the wind speed is calm")]
+fn check_wind_spd_calm(w: &mut World) {
+    let metar = w.metar();
+    assert_eq!(metar.wind, Wind::Calm);
+}
+
 #[then(expr = "the wind speed is {int} mps")]
 fn check_wind_spd_mps(w: &mut World, spd: u32) {
     let metar = w.metar();
@@ -228,7 +243,7 @@ fn wind_not_gusting(w: &mut World) {
             _ => panic!(),
         }
     } else {
-        panic!();
+        assert_eq!(metar.wind, Wind::Calm);
     }
 }
 
@@ -241,10 +256,16 @@ fn check_visibility_metres(w: &mut World, visibility: u16) {
 #[then(expr = "the visibility is {float} mi")]
 fn check_visibility_miles(w: &mut World, visibility: f32) {
     let metar = w.metar();
-    assert_eq!(
-        Visibility::StatuteMiles(visibility),
-        metar.visibility.unwrap()
-    );
+    match metar.visibility.unwrap() {
+        Visibility::StatuteMiles(miles) => assert_eq!(visibility, miles.to_f32()),
+        v => panic!("expected visibility in statute miles, found {v:?}"),
+    }
+}
+
+#[then(expr = "the visibility is 10 km+")]
+fn check_visibility_ten_km(w: &mut World) {
+    let metar = w.metar();
+    assert_eq!(Visibility::TenKilometresOrMore, metar.visibility.unwrap());
 }
 
 #[then(expr = "the visibility is CAVOK")]
@@ -295,10 +316,8 @@ fn check_pressure_hpa(w: &mut World, pressure: u16) {
 #[then(expr = "the pressure is {float} inHg")]
 fn check_pressure_inhg(w: &mut World, pressure: f32) {
     let metar = w.metar();
-    assert_eq!(
-        Pressure::InchesOfMercury(Data::Known(pressure)),
-        metar.pressure
-    );
+    assert_eq!(Some(pressure), metar.pressure.inches_of_mercury());
+    assert!(matches!(metar.pressure, Pressure::InchesOfMercury(_)));
 }
 
 #[then(expr = "the pressure is unknown")]
@@ -310,6 +329,212 @@ fn check_pressure_unk(w: &mut World) {
     }
 }
 
+fn check_clouds(clouds: &Clouds, sky: &str) {
+    match sky {
+        "SKC" => assert_eq!(*clouds, Clouds::SkyClear),
+        "CLR" => assert_eq!(*clouds, Clouds::Clear),
+        "NCD" => assert_eq!(*clouds, Clouds::NoCloudDetected),
+        "NSC" => assert_eq!(*clouds, Clouds::NoSignificantCloud),
+        layers => assert_eq!(clouds.layers().len(), layers.parse().unwrap()),
+    }
+}
+
+#[then(expr = "the sky condition is {word}")]
+fn check_sky_condition(w: &mut World, sky: String) {
+    check_clouds(&w.metar().clouds, &sky);
+}
+
+#[then(expr = "the effective sky condition is {word}")]
+fn check_effective_sky_condition(w: &mut World, sky: String) {
+    check_clouds(&w.metar().effective_conditions().clouds, &sky);
+}
+
+#[then(expr = "the effective visibility is 10 km+")]
+fn check_effective_visibility_ten_km(w: &mut World) {
+    let conditions = w.metar().effective_conditions();
+    assert_eq!(
+        conditions.visibility,
+        Data::Known(Visibility::TenKilometresOrMore)
+    );
+}
+
+#[then(expr = "the effective visibility is unknown")]
+fn check_effective_visibility_unk(w: &mut World) {
+    assert_eq!(w.metar().effective_conditions().visibility, Data::Unknown);
+}
+
+#[then(expr = "the lowest cloud layer is at {int} ft or {int} m")]
+fn check_lowest_cloud_layer(w: &mut World, feet: u32, metres: u32) {
+    let layer = w.metar().clouds.layers().first().unwrap();
+    assert_eq!(layer.height_feet(), Some(feet));
+    assert_eq!(layer.height_metres(), Some(metres));
+}
+
+fn parse_runway(runway: &str) -> Runway {
+    let (number, designator) = match runway.strip_suffix(['L', 'C', 'R']) {
+        Some(number) => (number, runway.chars().last()),
+        None => (runway, None),
+    };
+    Runway::Specific {
+        number: number.parse().unwrap(),
+        designator: designator.map(|designator| match designator {
+            'L' => RunwayDesignator::Left,
+            'C' => RunwayDesignator::Centre,
+            _ => RunwayDesignator::Right,
+        }),
+    }
+}
+
+#[then(expr = "the runway condition codes for runway {word} are {word}")]
+fn check_runway_condition_codes(w: &mut World, runway: String, codes: String) {
+    let runway = parse_runway(&runway);
+    let condition = w
+        .metar()
+        .runway_conditions
+        .iter()
+        .find(|condition| condition.applies_to(runway))
+        .unwrap();
+    let RunwayContamination::Assessed { thirds } = &condition.contamination else {
+        panic!(
+            "expected an assessment, found {:?}",
+            condition.contamination
+        );
+    };
+    let actual = thirds
+        .iter()
+        .map(|third| third.condition_code.to_string())
+        .collect::<Vec<_>>()
+        .join("/");
+    assert_eq!(actual, codes);
+}
+
+#[then(expr = "there is a runway condition for runway {word}")]
+fn check_runway_condition(w: &mut World, runway: String) {
+    let runway = parse_runway(&runway);
+    assert!(w
+        .metar()
+        .runway_conditions
+        .iter()
+        .any(|condition| condition.applies_to(runway)));
+}
+
+#[then(expr = "runway {word} is closed by snow")]
+fn check_runway_closed_by_snow(w: &mut World, runway: String) {
+    let runway = parse_runway(&runway);
+    assert!(w.metar().runway_conditions.iter().any(|condition| {
+        condition.applies_to(runway) && condition.contamination == RunwayContamination::ClosedBySnow
+    }));
+}
+
+#[then(expr = "windshear affects {word} on runway {word}")]
+fn check_windshear(w: &mut World, phase: String, runway: String) {
+    let runway = parse_runway(&runway);
+    let warnings = w.metar().windshear_warnings.as_ref().unwrap();
+    assert!(warnings
+        .affecting(runway)
+        .any(|group| match phase.as_str() {
+            "landing" => group.phase.affects_landing(),
+            "take-off" => group.phase.affects_take_off(),
+            _ => panic!("unknown phase {phase}"),
+        }));
+}
+
+fn parse_colour_code(code: &str) -> Option<ColourCode> {
+    match code {
+        "BLU" => Some(ColourCode::Blue),
+        "WHT" => Some(ColourCode::White),
+        "GRN" => Some(ColourCode::Green),
+        "YLO" => Some(ColourCode::Yellow),
+        "AMB" => Some(ColourCode::Amber),
+        "RED" => Some(ColourCode::Red),
+        "none" => None,
+        _ => panic!("unknown colour code {code}"),
+    }
+}
+
+#[then(expr = "the colour code is {word}")]
+fn check_colour_code(w: &mut World, code: String) {
+    let metar = w.metar();
+    assert_eq!(
+        parse_colour_code(&code),
+        metar.colour_code.map(|state| state.colour)
+    );
+}
+
+#[then(expr = "the airfield black state is {word}")]
+fn check_colour_code_black(w: &mut World, black: String) {
+    let metar = w.metar();
+    assert_eq!(
+        black == "true",
+        metar.colour_code.is_some_and(|state| state.black)
+    );
+}
+
+#[then(expr = "the derived colour code is {word}")]
+fn check_derived_colour_code(w: &mut World, code: String) {
+    let metar = w.metar();
+    assert_eq!(parse_colour_code(&code), metar.derive_colour_code());
+}
+
+#[then(expr = "the first trend colour code is {word}")]
+fn check_trend_colour_code(w: &mut World, code: String) {
+    let metar = w.metar();
+    match metar.trends.first() {
+        Some(Trend::Becoming(cond) | Trend::Temporarily(cond)) => assert_eq!(
+            parse_colour_code(&code),
+            cond.colour_code.map(|state| state.colour)
+        ),
+        _ => panic!(),
+    }
+}
+
+fn first_trend_condition(w: &World) -> &TrendNewCondition {
+    match w.metar().trends.first() {
+        Some(Trend::Becoming(cond) | Trend::Temporarily(cond)) => cond,
+        trend => panic!("expected a trend with conditions, found {trend:?}"),
+    }
+}
+
+#[then(expr = "the first trend has probability {word}")]
+fn check_trend_probability(w: &mut World, probability: String) {
+    let expected = match probability.as_str() {
+        "none" => None,
+        probability => Some(probability.parse().unwrap()),
+    };
+    assert_eq!(first_trend_condition(w).probability, expected);
+}
+
+#[then(expr = "the first trend CAVOK is {word}")]
+fn check_trend_cavok(w: &mut World, cavok: String) {
+    assert_eq!(
+        first_trend_condition(w).visibility == Some(Visibility::CAVOK),
+        cavok.parse::<bool>().unwrap()
+    );
+}
+
+#[then(expr = "the first trend sky condition is {word}")]
+fn check_trend_sky_condition(w: &mut World, sky: String) {
+    match (&first_trend_condition(w).clouds, sky.as_str()) {
+        (None, "none") => (),
+        (Some(clouds), sky) => check_clouds(clouds, sky),
+        (clouds, sky) => panic!("expected {sky}, found {clouds:?}"),
+    }
+}
+
+#[then(expr = "the report is automatic {word}, delayed {word} and nil {word}")]
+fn check_kind(w: &mut World, automatic: String, delayed: String, nil: String) {
+    let kind = w.metar().kind;
+    assert_eq!(kind.automatic, automatic.parse::<bool>().unwrap());
+    assert_eq!(kind.delayed, delayed.parse::<bool>().unwrap());
+    assert_eq!(kind.nil, nil.parse::<bool>().unwrap());
+}
+
+#[then(expr = "the correction is {word}")]
+fn check_correction(w: &mut World, correction: String) {
+    let found = w.metar().kind.correction.map(|c| c.to_string());
+    assert_eq!(found.as_deref().unwrap_or("none"), correction);
+}
+
 fn main() {
     futures::executor::block_on(World::run("tests/features"));
 }
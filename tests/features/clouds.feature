@@ -0,0 +1,38 @@
+Feature: Sky condition
+    The sky condition is reported either as a clear sky code or as a set of
+    cloud layers, with heights in hundreds of feet.
+
+    Scenario Outline: Parse the sky condition
+        When "<metar>" is parsed
+        Then it parses successfully
+        And the sky condition is <sky>
+
+        Examples:
+            | sky | metar                                                        |
+            | SKC | CYWG 190500Z 16014KT 10SM SKC 19/11 A2959                    |
+            | CLR | KEEN 061356Z AUTO 00000KT 10SM CLR 06/M03 A3029              |
+            | NCD | EGPC 061950Z AUTO 03004KT 9999 NCD 11/09 Q1017               |
+            | NSC | EGHI 131950Z 06001KT 9999 MIFG NSC 09/08 Q1010               |
+            | 3   | EGLL 121150Z 24012KT 9999 FEW012 ///CB BKN///TCU 12/05 Q1020 |
+
+    Scenario Outline: Convert cloud layer heights
+        When "<metar>" is parsed
+        Then it parses successfully
+        And the lowest cloud layer is at <feet> ft or <metres> m
+
+        Examples:
+            | feet | metres | metar                                               |
+            | 1200 | 366    | EGLL 121150Z 24012KT 9999 FEW012 BKN030 12/05 Q1020 |
+            | 300  | 91     | EGHI 150650Z 06001KT 3000 BR SCT003 11/10 Q1003     |
+
+    Scenario Outline: Expand CAVOK into the conditions it implies
+        When "<metar>" is parsed
+        Then it parses successfully
+        And the effective visibility is <visibility>
+        And the effective sky condition is <sky>
+
+        Examples:
+            | visibility | sky | metar                                          |
+            | 10 km+     | NSC | EGHI 062050Z 31006KT 270V340 CAVOK 13/07 Q1017 |
+            | 10 km+     | NCD | EGPC 061950Z AUTO 03004KT 9999 NCD 11/09 Q1017 |
+            | unknown    | SKC | CYWG 190500Z 16014KT SKC 19/11 A2959           |
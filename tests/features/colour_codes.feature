@@ -0,0 +1,31 @@
+Feature: Military colour codes
+    Military airfields report a colour state describing the visibility and
+    cloud base, which can also be derived from the rest of the METAR.
+
+    Scenario Outline: Parse and derive colour codes
+        When "<metar>" is parsed
+        Then it parses successfully
+        And the colour code is <reported>
+        And the airfield black state is <black>
+        And the derived colour code is <derived>
+
+        Examples:
+            | reported | black | derived | metar                                                                   |
+            | YLO      | false | YLO     | ETHB 112350Z 27004KT 3500 -DZ BR OVC007 02/01 Q1031 YLO                 |
+            | BLU      | true  | BLU     | EGXC 121150Z 24012KT 9999 FEW030 12/05 Q1020 BLACKBLU NOSIG             |
+            | WHT      | false | WHT     | EGVN 121150Z 24012KT 9999 SCT020 BKN040 12/05 Q1020 WHT                 |
+            | GRN      | false | GRN     | EGVN 121150Z 24012KT 4000 -RA BKN012 12/05 Q1020 GRN BECMG 9999 SCT025 BLU |
+            | none     | false | BLU     | EGHI 062050Z 31006KT 270V340 CAVOK 13/07 Q1017                          |
+            | none     | false | AMB     | EGHI 150650Z 06001KT 0800 FG VV002 11/10 Q1003                          |
+            | none     | false | RED     | EGHI 150650Z 06001KT 0500 R20/1000 FG VV/// 11/10 Q1003                 |
+
+    Scenario Outline: Parse colour codes in trends
+        When "<metar>" is parsed
+        Then it parses successfully
+        And the first trend colour code is <trend>
+
+        Examples:
+            | trend | metar                                                                           |
+            | BLU   | EGVN 121150Z 24012KT 4000 -RA BKN012 12/05 Q1020 GRN BECMG 9999 SCT025 BLU      |
+            | AMB   | EGXC 121150Z 24012KT 9999 FEW030 12/05 Q1020 BLU TEMPO 2000 +SHRA BKN003CB AMB  |
+            | WHT   | EGXC 121150Z 24012KT 9999 FEW030 12/05 Q1020 BLACKBLU BECMG BLACKWHT           |
@@ -0,0 +1,20 @@
+Feature: Report kinds
+    A report may be automatic, a correction, delayed or missing. These
+    modifiers may be combined, before the station or after the time.
+
+    Scenario Outline: Parse report kinds
+        When "<metar>" is parsed
+        Then it parses successfully
+        And the report is automatic <auto>, delayed <rtd> and nil <nil>
+        And the correction is <correction>
+
+        Examples:
+            | auto  | rtd   | nil   | correction | metar                                                    |
+            | false | false | false | none       | EGLL 121150Z 24005KT 9999 NCD 15/10 Q1020                |
+            | true  | false | false | COR        | EGLL 121150Z AUTO COR 24005KT 9999 NCD 15/10 Q1020       |
+            | true  | false | false | COR        | METAR COR EGLL 121150Z AUTO 24005KT 9999 NCD 15/10 Q1020 |
+            | false | false | false | CCA        | EGLL 121150Z CCA 24005KT 9999 NCD 15/10 Q1020            |
+            | true  | false | false | CCB        | METAR CCB EGLL 121150Z AUTO 24005KT 9999 NCD 15/10 Q1020 |
+            | false | true  | false | none       | UUEE 121200Z RTD 27003MPS 9999 SCT030 12/05 Q1015        |
+            | false | false | true  | none       | EGLL 121150Z NIL=                                        |
+            | false | false | true  | none       | METAR EGLL 121150Z NIL                                   |
@@ -0,0 +1,37 @@
+Feature: Runway conditions
+    The state of a runway is reported either in the legacy eight figure format
+    or as runway condition codes for each third of the runway, in the ICAO
+    Global Reporting Format.
+
+    Scenario Outline: Parse runway condition codes
+        When "<metar>" is parsed
+        Then it parses successfully
+        And the runway condition codes for runway <runway> are <codes>
+
+        Examples:
+            | runway | codes | metar                                                                             |
+            | 09L    | 5/5/2 | EFHK 121150Z 24012KT 9999 -SN BKN012 M02/M05 Q1020 R09L/5/5/2 100/100/50 NR/NR/03 |
+            | 27     | 6/6/6 | ESSA 121150Z 24012KT 9999 FEW030 M02/M05 Q1020 R27/6/6/6 NOSIG                    |
+
+    Scenario Outline: Parse runways closed by snow
+        When "<metar>" is parsed
+        Then it parses successfully
+        And runway <runway> is closed by snow
+
+        Examples:
+            | runway | metar                                                        |
+            | 24     | ULLI 121150Z 24012KT 0800 +SN VV004 M02/M05 Q1020 R/SNOCLO   |
+            | 06     | ULLI 121150Z 24012KT 0800 +SN VV004 M02/M05 Q1020 R06/SNOCLO |
+            | 10     | ULLI 121150Z 24012KT 0800 +SN VV004 M02/M05 Q1020 R88/SNOCLO |
+
+    Scenario Outline: Parse eight figure runway state groups
+        When "<metar>" is parsed
+        Then it parses successfully
+        And there is a runway condition for runway <runway>
+
+        Examples:
+            | runway | metar                                                            |
+            | 24R    | UUEE 121150Z 24012KT 9999 BKN012 M02/M05 Q1020 74290195 NOSIG    |
+            | 06     | UUEE 121150Z 24012KT 9999 BKN012 M02/M05 Q1020 06CLRD93 56CLRD95 |
+            | 06R    | UUEE 121150Z 24012KT 9999 BKN012 M02/M05 Q1020 06CLRD93 56CLRD95 |
+            | 10     | UUEE 121150Z 24012KT 9999 BKN012 M02/M05 Q1020 88290195          |
@@ -0,0 +1,18 @@
+Feature: Trends
+    A METAR may end with trend groups forecasting changes in the next two
+    hours, which may become permanent (BECMG) or be temporary (TEMPO).
+
+    Scenario Outline: Parse trend conditions
+        When "<metar>" is parsed
+        Then it parses successfully
+        And the first trend has probability <probability>
+        And the first trend CAVOK is <cavok>
+        And the first trend sky condition is <sky>
+
+        Examples:
+            | probability | cavok | sky  | metar                                                                               |
+            | none        | false | NSC  | EGLL 121150Z 24012KT 9999 -RA BKN012 12/05 Q1020 BECMG NSC                          |
+            | none        | true  | none | EGLL 121150Z 24012KT 4000 -RA BKN012 12/05 Q1020 TEMPO CAVOK                        |
+            | 30          | false | 1    | LIRF 121150Z 24012KT 9999 FEW030 22/15 Q1012 PROB30 TEMPO TL1400 3000 TSRA FEW025CB |
+            | 40          | false | none | LIRF 121150Z 24012KT 9999 FEW030 22/15 Q1012 PROB40 TEMPO FM1300 4000 SHRA          |
+            | none        | false | 2    | EGLL 121150Z 24012KT 9999 FEW012 12/05 Q1020 BECMG AT1230 NSW SCT020 BKN040         |
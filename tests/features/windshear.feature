@@ -0,0 +1,17 @@
+Feature: Windshear warnings
+    Windshear may be reported for all runways or for specific runways, and
+    for landing, take-off or both.
+
+    Scenario Outline: Parse windshear warnings
+        When "<metar>" is parsed
+        Then it parses successfully
+        And windshear affects <phase> on runway <runway>
+
+        Examples:
+            | phase    | runway | metar                                                                             |
+            | landing  | 34L    | RJAA 070900Z 03010KT 350V050 9999 -SHRA FEW010 BKN017 16/14 Q1004 WS R34L NOSIG   |
+            | take-off | 34L    | RJAA 070900Z 03010KT 350V050 9999 -SHRA FEW010 BKN017 16/14 Q1004 WS RWY34L NOSIG |
+            | landing  | 24     | OMDB 121150Z 24012KT 9999 FEW030 32/20 Q1008 WS LDG RWY24 NOSIG                   |
+            | take-off | 12     | OMDB 121150Z 24012KT 9999 FEW030 32/20 Q1008 WS TKOF RWY12 NOSIG                  |
+            | landing  | 06     | OMDB 121150Z 24012KT 9999 FEW030 32/20 Q1008 WS ALL RWY WS TKOF RWY12             |
+            | take-off | 12     | OMDB 121150Z 24012KT 9999 FEW030 32/20 Q1008 WS ALL RWY WS TKOF RWY12             |